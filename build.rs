@@ -1,3 +1,7 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
 fn main() {
     // Forward the profile to the main compilation
     println!("cargo:rustc-env=PROFILE={}", std::env::var("PROFILE").unwrap());
@@ -16,4 +20,195 @@ fn main() {
         "cargo:rustc-env=RUSTC_RELEASE_CHANNEL={}",
         rustc_tools_util::get_channel().unwrap_or_default()
     );
+    // embed the lint documentation for `cargo clippy --explain`
+    println!("cargo:rerun-if-changed=clippy_lints/src");
+    write_lint_docs();
+}
+
+/// A lint declared with `declare_clippy_lint!` or `declare_deprecated_lint!`.
+struct Lint {
+    name: String,
+    group: String,
+    docs: Vec<String>,
+}
+
+/// A configuration value documented with `/// Lint: ...` or `/// General: ...` in `define_Conf!`.
+struct Config {
+    /// The lints affected by the value, none for the general configuration.
+    lints: Vec<String>,
+    name: String,
+    ty: String,
+    default: String,
+    doc: String,
+}
+
+/// Writes the documentation of the lints and of their configuration values to
+/// `$OUT_DIR/lint_docs.rs`, the way `util/export.py` collects it for the website.
+fn write_lint_docs() {
+    let mut files = Vec::new();
+    collect_rs_files(Path::new("clippy_lints/src"), &mut files);
+    files.sort();
+
+    let mut lints = Vec::new();
+    for file in &files {
+        parse_lints(&fs::read_to_string(file).unwrap(), &mut lints);
+    }
+    lints.sort_by(|a, b| a.name.cmp(&b.name));
+    let configs = parse_configs(&fs::read_to_string("clippy_lints/src/utils/conf.rs").unwrap());
+
+    let mut out = String::from("&[\n");
+    for lint in lints {
+        let config: Vec<_> = configs
+            .iter()
+            .filter(|config| config.lints.contains(&lint.name))
+            .map(|config| {
+                format!(
+                    "Config {{ name: {}, ty: {}, default: {}, doc: {} }}",
+                    literal(&config.name),
+                    literal(&config.ty),
+                    literal(&config.default),
+                    literal(&config.doc)
+                )
+            })
+            .collect();
+        writeln!(
+            out,
+            "    LintDoc {{ name: {}, group: {}, docs: {}, config: &[{}] }},",
+            literal(&lint.name),
+            literal(&lint.group),
+            literal(&lint.docs.join("\n")),
+            config.join(", ")
+        )
+        .unwrap();
+    }
+    out.push(']');
+
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("lint_docs.rs"), out).unwrap();
+}
+
+/// A string literal for `s`, with non-ASCII characters escaped.
+fn literal(s: &str) -> String {
+    format!("\"{}\"", s.escape_default())
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_rs_files(&path, files);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Parses the lint declarations of a file, like `parse_lints` in `util/lintlib.py`.
+fn parse_lints(content: &str, lints: &mut Vec<Lint>) {
+    let mut source = content.lines();
+    while let Some(line) = source.next() {
+        let deprecated = if line.starts_with("declare_clippy_lint!") {
+            false
+        } else if line.starts_with("declare_deprecated_lint!") {
+            true
+        } else {
+            continue;
+        };
+
+        let mut docs = Vec::new();
+        let name = loop {
+            let line = match source.next() {
+                Some(line) => line.trim(),
+                None => return,
+            };
+            if let Some(doc) = line.strip_prefix("///") {
+                docs.push(doc.strip_prefix(' ').unwrap_or(doc).to_string());
+            } else if let Some(name) = line.strip_prefix("pub ") {
+                break name.trim_end_matches(',').trim().to_lowercase();
+            }
+        };
+        let group = if deprecated {
+            "deprecated".to_string()
+        } else {
+            match source.next() {
+                Some(group) => group.trim().trim_end_matches(',').to_string(),
+                None => return,
+            }
+        };
+        // internal lints are not documented
+        if !group.starts_with("internal") {
+            lints.push(Lint { name, group, docs });
+        }
+    }
+}
+
+/// Parses the `/// Lint: ...` and `/// General: ...` entries of `define_Conf!`, like
+/// `parse_configs` in `util/lintlib.py`.
+fn parse_configs(content: &str) -> Vec<Config> {
+    let mut configs = Vec::new();
+    let mut lines = content.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        let (lint_names, doc) = if let Some(doc) = line.strip_prefix("/// General: ") {
+            (None, doc)
+        } else {
+            match line.strip_prefix("/// Lint: ").and_then(|line| split_once(line, ". ")) {
+                Some((lint_names, doc)) => (Some(lint_names), doc),
+                None => continue,
+            }
+        };
+
+        // the entry may span several lines, e.g. `doc-valid-idents`
+        let mut entry = String::new();
+        for line in &mut lines {
+            entry.push_str(line);
+            entry.push(' ');
+            if line.ends_with("),") {
+                break;
+            }
+        }
+        let entry = entry.trim_end().trim_end_matches("),");
+        let (name, rest) = match split_once(entry, "\"").and_then(|(_, rest)| split_once(rest, "\": ")) {
+            Some(entry) => entry,
+            None => continue,
+        };
+
+        // the type ends at the first comma that isn't part of its generic arguments
+        let mut depth = 0;
+        let ty_end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    ',' if depth == 0 => return true,
+                    _ => {},
+                }
+                false
+            })
+            .map_or(rest.len(), |(i, _)| i);
+        let default = rest[ty_end..].trim_start_matches(',').trim();
+        let default = default
+            .strip_suffix(".iter().map(ToString::to_string).collect()")
+            .unwrap_or(default);
+
+        configs.push(Config {
+            lints: lint_names.map_or_else(Vec::new, |lint_names| {
+                lint_names.split(',').map(|lint| lint.trim().to_lowercase()).collect()
+            }),
+            name: name.replace('_', "-"),
+            ty: rest[..ty_end].trim().to_string(),
+            default: default
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace(", ]", "]"),
+            doc: doc.to_string(),
+        });
+    }
+    configs
+}
+
+/// `str::split_once`, which isn't stable yet.
+fn split_once<'a>(s: &'a str, pat: &str) -> Option<(&'a str, &'a str)> {
+    s.find(pat).map(|i| (&s[..i], &s[i + pat.len()..]))
 }
@@ -15,6 +15,7 @@ pub mod fmt;
 pub mod lintcheck;
 pub mod new_lint;
 pub mod ra_setup;
+pub mod reduce;
 pub mod serve;
 pub mod stderr_length_check;
 pub mod update_lints;
@@ -43,6 +44,17 @@ static DEC_DEPRECATED_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
     )
     .unwrap()
 });
+static DEC_LINT_PASS_RE: SyncLazy<Regex> = SyncLazy::new(|| {
+    Regex::new(
+        r#"(?x)
+    (?:declare|impl)_lint_pass!\s*[{(]
+    (?:\s*(?:\#\[[^\]]*\]|///.*))*
+    \s*(?P<name>[A-Za-z_][A-Za-z_0-9]*)(?:<[^>]*>)?\s*=>\s*
+    \[(?P<lints>[^\]]*)\]
+"#,
+    )
+    .unwrap()
+});
 static NL_ESCAPE_RE: SyncLazy<Regex> = SyncLazy::new(|| Regex::new(r#"\\\n\s*"#).unwrap());
 
 pub static DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
@@ -98,6 +110,14 @@ impl Lint {
     }
 }
 
+/// Lint pass data parsed from the Clippy source code.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LintPass {
+    pub name: String,
+    /// The names of the lints of the pass, in lowercase.
+    pub lints: Vec<String>,
+}
+
 /// Generates the Vec items for `register_lint_group` calls in `clippy_lints/src/lib.rs`.
 #[must_use]
 pub fn gen_lint_group_list<'a>(lints: impl Iterator<Item = &'a Lint>) -> Vec<String> {
@@ -174,6 +194,43 @@ pub fn gen_register_lint_list<'a>(
     lint_list
 }
 
+/// Generates the `PASS_LINTS` list in `clippy_lints/src/lib.rs`. The passes which declare no lints,
+/// or lints that are not in `lints`, are left out, as they always run.
+#[must_use]
+pub fn gen_pass_lints<'a>(passes: impl Iterator<Item = &'a LintPass>, lints: &[&Lint]) -> Vec<String> {
+    passes
+        .filter(|pass| {
+            !pass.lints.is_empty()
+                && pass
+                    .lints
+                    .iter()
+                    .all(|name| lints.iter().any(|lint| lint.name == *name))
+        })
+        .sorted_by_key(|pass| &pass.name)
+        .flat_map(|pass| {
+            let names: Vec<_> = pass.lints.iter().map(|name| format!("\"{}\"", name)).collect();
+            let line = format!("    (\"{}\", &[{}]),", pass.name, names.join(", "));
+            if line.len() <= 120 {
+                return vec![line];
+            }
+            let mut wrapped = vec![format!("    (\"{}\", &[", pass.name)];
+            let mut line = String::from("       ");
+            for name in names {
+                if line.len() + name.len() + 2 > 120 {
+                    wrapped.push(line);
+                    line = String::from("       ");
+                }
+                line.push(' ');
+                line.push_str(&name);
+                line.push(',');
+            }
+            wrapped.push(line);
+            wrapped.push("    ]),".to_string());
+            wrapped
+        })
+        .collect()
+}
+
 /// Gathers all files in `src/clippy_lints` and gathers all lints inside
 pub fn gather_all() -> impl Iterator<Item = Lint> {
     lint_files().flat_map(|f| gather_from_file(&f))
@@ -213,6 +270,30 @@ fn parse_contents(content: &str, module: &str) -> impl Iterator<Item = Lint> {
     lints.chain(deprecated).collect::<Vec<Lint>>().into_iter()
 }
 
+/// Gathers all files in `src/clippy_lints` and gathers all lint passes inside
+pub fn gather_lint_passes() -> impl Iterator<Item = LintPass> {
+    lint_files().flat_map(|f| gather_lint_passes_from_file(&f))
+}
+
+fn gather_lint_passes_from_file(dir_entry: &walkdir::DirEntry) -> Vec<LintPass> {
+    parse_lint_passes(&fs::read_to_string(dir_entry.path()).unwrap())
+}
+
+fn parse_lint_passes(content: &str) -> Vec<LintPass> {
+    DEC_LINT_PASS_RE
+        .captures_iter(content)
+        .map(|m| LintPass {
+            name: m["name"].to_string(),
+            lints: m["lints"]
+                .split(',')
+                .map(str::trim)
+                .filter(|lint| !lint.is_empty())
+                .map(|lint| lint.rsplit("::").next().unwrap().to_lowercase())
+                .collect(),
+        })
+        .collect()
+}
+
 /// Collects all .rs files in the `clippy_lints/src` directory
 fn lint_files() -> impl Iterator<Item = walkdir::DirEntry> {
     // We use `WalkDir` instead of `fs::read_dir` here in order to recurse into subdirectories.
@@ -402,6 +483,42 @@ declare_deprecated_lint! {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn test_parse_lint_passes() {
+    let result = parse_lint_passes(
+        r#"
+declare_lint_pass!(Attributes => [INLINE_ALWAYS, DEPRECATED_SEMVER]);
+
+impl_lint_pass!(Types<'_> => [
+    BOX_VEC,
+    crate::types::VEC_BOX,
+]);
+
+declare_lint_pass! {
+    /// some doc comment
+    #[derive(Default)]
+    Unused => []
+}
+    "#,
+    );
+
+    let expected = vec![
+        LintPass {
+            name: "Attributes".to_string(),
+            lints: vec!["inline_always".to_string(), "deprecated_semver".to_string()],
+        },
+        LintPass {
+            name: "Types".to_string(),
+            lints: vec!["box_vec".to_string(), "vec_box".to_string()],
+        },
+        LintPass {
+            name: "Unused".to_string(),
+            lints: vec![],
+        },
+    ];
+    assert_eq!(expected, result);
+}
+
 #[test]
 fn test_replace_region() {
     let text = "\nabc\n123\n789\ndef\nghi";
@@ -557,3 +674,29 @@ fn test_gen_lint_group_list() {
     ];
     assert_eq!(expected, gen_lint_group_list(lints.iter()));
 }
+
+#[test]
+fn test_gen_pass_lints() {
+    let lints = vec![
+        Lint::new("abc", "group1", "abc", None, "module_name"),
+        Lint::new("should_assert_eq", "group1", "abc", None, "module_name"),
+    ];
+    let pass = |name: &str, lints: &[&str]| LintPass {
+        name: name.to_string(),
+        lints: lints.iter().map(ToString::to_string).collect(),
+    };
+    let passes = vec![
+        pass("Second", &["should_assert_eq"]),
+        pass("First", &["abc", "should_assert_eq"]),
+        pass("Unknown", &["abc", "not_a_clippy_lint"]),
+        pass("Empty", &[]),
+    ];
+    let expected = vec![
+        "    (\"First\", &[\"abc\", \"should_assert_eq\"]),".to_string(),
+        "    (\"Second\", &[\"should_assert_eq\"]),".to_string(),
+    ];
+    assert_eq!(
+        expected,
+        gen_pass_lints(passes.iter(), &lints.iter().collect::<Vec<_>>())
+    );
+}
@@ -411,6 +411,9 @@ pub fn run(clap_config: &ArgMatches) {
     text.push_str("ICEs:\n");
     ices.iter()
         .for_each(|(cratename, msg)| text.push_str(&format!("{}: '{}'", cratename, msg)));
+    if !ices.is_empty() {
+        println!("ICEs were found, `cargo dev reduce <crate path> --ice` can cut a crate down to a reproducer");
+    }
 
     let file = format!("lintcheck-logs/{}_logs.txt", filename);
     write(file, text).unwrap();
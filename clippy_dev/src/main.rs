@@ -1,7 +1,7 @@
 #![cfg_attr(feature = "deny-warnings", deny(warnings))]
 
 use clap::{App, Arg, ArgMatches, SubCommand};
-use clippy_dev::{bless, fmt, new_lint, ra_setup, serve, stderr_length_check, update_lints};
+use clippy_dev::{bless, fmt, new_lint, ra_setup, reduce, serve, stderr_length_check, update_lints};
 
 #[cfg(feature = "lintcheck")]
 use clippy_dev::lintcheck;
@@ -43,6 +43,15 @@ fn main() {
             stderr_length_check::check();
         },
         ("ra_setup", Some(matches)) => ra_setup::run(matches.value_of("rustc-repo-path")),
+        ("reduce", Some(matches)) => reduce::run(
+            matches.value_of("path").unwrap(),
+            matches.value_of("lint"),
+            matches.value_of("at"),
+            matches.value_of("output"),
+            matches
+                .values_of("clippy-args")
+                .map_or_else(Vec::new, |args| args.map(ToString::to_string).collect()),
+        ),
         ("serve", Some(matches)) => {
             let port = matches.value_of("port").unwrap().parse().unwrap();
             let lint = matches.value_of("lint");
@@ -176,6 +185,51 @@ fn get_clap_config<'a>() -> ArgMatches<'a> {
                         .required(true),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("reduce")
+                .about("Reduce a crate or a file on which Clippy crashes or emits a lint to a minimal reproducer")
+                .arg(
+                    Arg::with_name("path")
+                        .help("The directory of the crate or the file to reduce")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("ice")
+                        .long("ice")
+                        .help("Keep Clippy crashing at the same place")
+                        .conflicts_with("lint")
+                        .required_unless("lint"),
+                )
+                .arg(
+                    Arg::with_name("lint")
+                        .long("lint")
+                        .help("Keep the lint emitted, ex: needless_return")
+                        .takes_value(true)
+                        .value_name("LINT"),
+                )
+                .arg(
+                    Arg::with_name("at")
+                        .long("at")
+                        .help("Keep the lint emitted at this line, and this file relative to the crate for a crate")
+                        .takes_value(true)
+                        .value_name("[FILE:]LINE")
+                        .requires("lint"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .help("Copy the reproducer there, instead of leaving it in target/reduce")
+                        .takes_value(true)
+                        .value_name("path"),
+                )
+                .arg(
+                    Arg::with_name("clippy-args")
+                        .help("Arguments passed to Clippy, ex: -- --edition=2015")
+                        .multiple(true)
+                        .last(true),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("serve")
                 .about("Launch a local 'ALL the Clippy Lints' website in a browser")
@@ -0,0 +1,799 @@
+//! `reduce` cuts a crate or a file on which Clippy crashes or emits a lint down to a minimal
+//! reproducer, by deleting the items, statements and expressions which aren't needed for that.
+//!
+//! The sources are edited as text, using the spans of their `syn` syntax tree, so that the comments
+//! and the formatting of what is left are kept.
+
+use crate::clippy_project_root;
+use proc_macro2::{LineColumn, Span};
+use serde_json::Value;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use walkdir::WalkDir;
+
+/// What has to stay true while reducing.
+enum Predicate {
+    /// Clippy crashes, at the same place as at first.
+    Ice,
+    /// Clippy emits the lint, with the code `clippy::<name>`.
+    Lint(String),
+}
+
+/// How Clippy is run on the copy being reduced.
+enum Target {
+    /// A file compiled on its own by `clippy-driver`.
+    File { file: PathBuf, crate_type: &'static str },
+    /// A crate checked by `cargo clippy`.
+    Crate,
+}
+
+/// The line a lint has to be emitted at, followed through the edits of its file.
+#[derive(Clone, Debug, PartialEq)]
+struct Marker {
+    /// The path of the file, relative to the directory Clippy is run in.
+    file: PathBuf,
+    /// The byte range of the line in the current text of the file, without the newline.
+    range: Range<usize>,
+}
+
+impl Marker {
+    fn new(file: PathBuf, text: &str, line: usize) -> Option<Self> {
+        let start = *line_starts(text).get(line.checked_sub(1)?)?;
+        let end = text[start..].find('\n').map_or(text.len(), |end| start + end);
+        Some(Self {
+            file,
+            range: start..end,
+        })
+    }
+
+    /// The number of the line, starting at 1.
+    fn line(&self, text: &str) -> usize {
+        text[..self.range.start].matches('\n').count() + 1
+    }
+
+    /// Follows the line through `edit` of its file. Returns `None` if the edit deletes the line or
+    /// joins it with another one.
+    fn after(&self, edit: &Edit) -> Option<Self> {
+        let shift = |offset: usize| offset - edit.range.len() + edit.replacement.len();
+        let range = if edit.range.end <= self.range.start {
+            shift(self.range.start)..shift(self.range.end)
+        } else if edit.range.start > self.range.end {
+            self.range.clone()
+        } else if edit.range.start >= self.range.start && edit.range.end <= self.range.end {
+            self.range.start..shift(self.range.end)
+        } else {
+            return None;
+        };
+        Some(Self {
+            file: self.file.clone(),
+            range,
+        })
+    }
+}
+
+/// Replaces a range of a text.
+#[derive(Clone, Debug, PartialEq)]
+struct Edit {
+    range: Range<usize>,
+    replacement: String,
+}
+
+impl Edit {
+    fn apply(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        text.replace_range(self.range.clone(), &self.replacement);
+        text
+    }
+
+    /// How much shorter the edit makes the text.
+    fn removed(&self) -> usize {
+        self.range.len().saturating_sub(self.replacement.len())
+    }
+}
+
+/// What Clippy reported in one run.
+#[derive(Debug, Default)]
+struct Outcome {
+    /// Where the compiler panicked, if it crashed. This is empty if the crash didn't say.
+    ice: Option<String>,
+    /// The number of errors, other than the crash and the lints.
+    errors: usize,
+    /// The codes of the lints emitted, with the file and the lines of their primary span.
+    lints: Vec<(String, PathBuf, RangeInclusive<usize>)>,
+}
+
+struct Reducer {
+    /// The directory Clippy is run in, containing the copy being reduced.
+    dir: PathBuf,
+    target: Target,
+    predicate: Predicate,
+    marker: Option<Marker>,
+    /// Where Clippy crashed at first.
+    ice: Option<String>,
+    /// The number of errors at first, which the reduced code can't exceed.
+    errors: usize,
+    clippy_args: Vec<String>,
+    runs: usize,
+}
+
+impl Reducer {
+    fn run_clippy(&mut self) -> Outcome {
+        let target_dir = clippy_project_root().join("target").join("debug");
+        let mut command = match &self.target {
+            Target::File { file, crate_type } => {
+                let mut command = Command::new(target_dir.join("clippy-driver"));
+                command
+                    .arg(file)
+                    .args(&["--error-format=json", "--emit=metadata", "--out-dir=target"]);
+                if !self.clippy_args.iter().any(|arg| arg.starts_with("--edition")) {
+                    command.arg("--edition=2018");
+                }
+                if !self.clippy_args.iter().any(|arg| arg.starts_with("--crate-type")) {
+                    command.arg(format!("--crate-type={}", crate_type));
+                }
+                command
+            },
+            Target::Crate => {
+                let mut command = Command::new(target_dir.join("cargo-clippy"));
+                command.args(&["clippy", "--quiet", "--message-format=json", "--"]);
+                command
+            },
+        };
+        if let Predicate::Lint(code) = &self.predicate {
+            command.arg(format!("-W{}", code));
+        }
+        let output = command
+            .args(&self.clippy_args)
+            .current_dir(&self.dir)
+            // keep the bundles written by the crashes out of the temporary directory
+            .env("CLIPPY_ICE_DIR", self.dir.join("target"))
+            .output()
+            .unwrap_or_else(|error| panic!("failed to run Clippy: {}", error));
+        self.runs += 1;
+        parse_output(
+            &String::from_utf8_lossy(&output.stdout),
+            &String::from_utf8_lossy(&output.stderr),
+        )
+    }
+
+    fn holds(&self, outcome: &Outcome, marker: Option<&Marker>) -> bool {
+        if outcome.errors > self.errors {
+            return false;
+        }
+        match &self.predicate {
+            Predicate::Ice => outcome.ice.is_some() && outcome.ice == self.ice,
+            Predicate::Lint(code) => {
+                let at = marker.map(|marker| {
+                    let text = fs::read_to_string(self.dir.join(&marker.file)).unwrap();
+                    (&marker.file, marker.line(&text))
+                });
+                outcome.lints.iter().any(|(lint, file, lines)| {
+                    lint == code && at.map_or(true, |(at_file, line)| file == at_file && lines.contains(&line))
+                })
+            },
+        }
+    }
+
+    /// The Rust files of the copy, relative to its directory.
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<_> = WalkDir::new(&self.dir)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "target")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().map_or(false, |extension| extension == "rs"))
+            .map(|entry| entry.path().strip_prefix(&self.dir).unwrap().to_path_buf())
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// The marker once `edit` is applied to `file`, or `Err` if the edit loses it.
+    fn marker_after(&self, file: &Path, edit: &Edit) -> Result<Option<Marker>, ()> {
+        match &self.marker {
+            Some(marker) if marker.file == file => marker.after(edit).map(Some).ok_or(()),
+            marker => Ok(marker.clone()),
+        }
+    }
+
+    /// Applies the edits of `file` which keep the predicate true, the largest first. Returns
+    /// whether any was.
+    fn reduce_file(&mut self, file: &Path) -> bool {
+        let path = self.dir.join(file);
+        let mut text = fs::read_to_string(&path).unwrap();
+        let mut reduced = false;
+        let mut start = 0;
+        // the edits are collected again after each one applied, as the spans move. Those which
+        // failed before are likely to fail again, so they are only tried again in the next pass.
+        'edits: while let Ok(edits) = edits(&text) {
+            for (i, edit) in edits.iter().enumerate().skip(start) {
+                let marker = match self.marker_after(file, edit) {
+                    Ok(marker) => marker,
+                    Err(()) => continue,
+                };
+                let edited = edit.apply(&text);
+                fs::write(&path, &edited).unwrap();
+                let outcome = self.run_clippy();
+                if self.holds(&outcome, marker.as_ref()) {
+                    println!(
+                        "{}: deleted {} bytes, {} left",
+                        file.display(),
+                        edit.removed(),
+                        edited.len()
+                    );
+                    text = edited;
+                    self.marker = marker;
+                    reduced = true;
+                    start = i;
+                    continue 'edits;
+                }
+            }
+            break;
+        }
+        fs::write(&path, &text).unwrap();
+        reduced
+    }
+
+    /// Reduces the files until no edit keeps the predicate true, then tidies them.
+    fn reduce(&mut self) {
+        while self
+            .files()
+            .iter()
+            .fold(false, |reduced, file| self.reduce_file(file) || reduced)
+        {}
+
+        let texts = self.tidy();
+        let outcome = self.run_clippy();
+        if !self.holds(&outcome, self.marker.as_ref()) {
+            for (file, text) in texts {
+                fs::write(self.dir.join(file), text).unwrap();
+            }
+        }
+    }
+
+    /// Deletes the blank lines left by the edits, keeping one between the remaining code. Returns
+    /// the texts of the files before, to restore them if this changes the outcome.
+    fn tidy(&mut self) -> Vec<(PathBuf, String)> {
+        let mut texts = Vec::new();
+        for file in self.files() {
+            let path = self.dir.join(&file);
+            let mut text = fs::read_to_string(&path).unwrap();
+            texts.push((file.clone(), text.clone()));
+            // from the end of the text, so that the ranges of the remaining edits stay valid
+            for edit in blank_line_edits(&text).iter().rev() {
+                if let Ok(marker) = self.marker_after(&file, edit) {
+                    text = edit.apply(&text);
+                    self.marker = marker;
+                }
+            }
+            fs::write(&path, &text).unwrap();
+        }
+        texts
+    }
+}
+
+/// Parses the JSON diagnostics of `clippy-driver` or `cargo clippy`, and the panic message in case
+/// of a crash.
+fn parse_output(stdout: &str, stderr: &str) -> Outcome {
+    let mut outcome = Outcome::default();
+    for line in stdout.lines().chain(stderr.lines()) {
+        if let Some(start) = line.find("panicked at '") {
+            // the message can change with the code, but the location of the panic doesn't
+            let location = line[start..].rfind("', ").map_or("", |end| &line[start + end + 3..]);
+            if outcome.ice.as_ref().map_or(true, String::is_empty) {
+                outcome.ice = Some(location.to_string());
+            }
+            continue;
+        }
+        if line.contains("internal compiler error") {
+            outcome.ice.get_or_insert_with(String::new);
+        }
+        let mut message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+        if message["reason"] == "compiler-message" {
+            message = message["message"].take();
+        }
+        let primary = message["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        let primary = match primary {
+            Some(primary) => primary,
+            None => continue,
+        };
+        match message["code"]["code"].as_str() {
+            Some(code) if code.starts_with("clippy::") => {
+                let line = |key: &str| primary[key].as_u64().and_then(|line| usize::try_from(line).ok());
+                if let (Some(file), Some(start), Some(end)) =
+                    (primary["file_name"].as_str(), line("line_start"), line("line_end"))
+                {
+                    outcome.lints.push((code.to_string(), PathBuf::from(file), start..=end));
+                }
+            },
+            _ if message["level"] == "error" => outcome.errors += 1,
+            _ => {},
+        }
+    }
+    outcome
+}
+
+/// The byte offsets of the starts of the lines of `text`.
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Collects the edits deleting nodes of the syntax tree, or replacing them with one of their parts.
+struct EditCollector<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+    edits: Vec<Edit>,
+}
+
+impl EditCollector<'_> {
+    fn offset(&self, position: LineColumn) -> usize {
+        let start = self.line_starts[position.line.saturating_sub(1)];
+        self.text[start..]
+            .char_indices()
+            .nth(position.column)
+            .map_or(self.text.len(), |(i, _)| start + i)
+    }
+
+    fn range(&self, span: Span) -> Range<usize> {
+        self.offset(span.start())..self.offset(span.end())
+    }
+
+    fn delete(&mut self, node: &impl Spanned) {
+        let range = self.range(node.span());
+        self.edits.push(Edit {
+            range,
+            replacement: String::new(),
+        });
+    }
+
+    /// Deletes the elements of a list, each with the separator and the spaces after it.
+    fn delete_elements<T: Spanned, P: Spanned>(&mut self, elements: &Punctuated<T, P>) {
+        for pair in elements.pairs() {
+            let start = self.offset(pair.value().span().start());
+            let end = match pair.punct() {
+                Some(punct) => {
+                    let end = self.offset(punct.span().end());
+                    end + self.text[end..].len() - self.text[end..].trim_start_matches(' ').len()
+                },
+                None => self.offset(pair.value().span().end()),
+            };
+            self.edits.push(Edit {
+                range: start..end,
+                replacement: String::new(),
+            });
+        }
+    }
+
+    fn replace_with_part(&mut self, node: &impl Spanned, part: &impl Spanned) {
+        let range = self.range(node.span());
+        let part = self.range(part.span());
+        self.edits.push(Edit {
+            range,
+            replacement: self.text[part].to_string(),
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for EditCollector<'_> {
+    fn visit_attribute(&mut self, attr: &'ast syn::Attribute) {
+        self.delete(attr);
+    }
+
+    fn visit_item(&mut self, item: &'ast syn::Item) {
+        self.delete(item);
+        visit::visit_item(self, item);
+    }
+
+    fn visit_impl_item(&mut self, item: &'ast syn::ImplItem) {
+        self.delete(item);
+        visit::visit_impl_item(self, item);
+    }
+
+    fn visit_trait_item(&mut self, item: &'ast syn::TraitItem) {
+        self.delete(item);
+        visit::visit_trait_item(self, item);
+    }
+
+    fn visit_foreign_item(&mut self, item: &'ast syn::ForeignItem) {
+        self.delete(item);
+        visit::visit_foreign_item(self, item);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast syn::Stmt) {
+        self.delete(stmt);
+        visit::visit_stmt(self, stmt);
+    }
+
+    fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+        self.delete(arm);
+        visit::visit_arm(self, arm);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
+        self.delete_elements(&item.variants);
+        visit::visit_item_enum(self, item);
+    }
+
+    fn visit_fields_named(&mut self, fields: &'ast syn::FieldsNamed) {
+        self.delete_elements(&fields.named);
+        visit::visit_fields_named(self, fields);
+    }
+
+    fn visit_fields_unnamed(&mut self, fields: &'ast syn::FieldsUnnamed) {
+        self.delete_elements(&fields.unnamed);
+        visit::visit_fields_unnamed(self, fields);
+    }
+
+    fn visit_expr_array(&mut self, expr: &'ast syn::ExprArray) {
+        self.delete_elements(&expr.elems);
+        visit::visit_expr_array(self, expr);
+    }
+
+    fn visit_expr_binary(&mut self, expr: &'ast syn::ExprBinary) {
+        self.replace_with_part(expr, &*expr.left);
+        self.replace_with_part(expr, &*expr.right);
+        visit::visit_expr_binary(self, expr);
+    }
+
+    fn visit_expr_call(&mut self, expr: &'ast syn::ExprCall) {
+        self.delete_elements(&expr.args);
+        visit::visit_expr_call(self, expr);
+    }
+
+    fn visit_expr_cast(&mut self, expr: &'ast syn::ExprCast) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_cast(self, expr);
+    }
+
+    fn visit_expr_field(&mut self, expr: &'ast syn::ExprField) {
+        self.replace_with_part(expr, &*expr.base);
+        visit::visit_expr_field(self, expr);
+    }
+
+    fn visit_expr_if(&mut self, expr: &'ast syn::ExprIf) {
+        if let Some((else_token, else_branch)) = &expr.else_branch {
+            let start = self.offset(else_token.span.start());
+            let end = self.offset(else_branch.span().end());
+            self.edits.push(Edit {
+                range: start..end,
+                replacement: String::new(),
+            });
+        }
+        visit::visit_expr_if(self, expr);
+    }
+
+    fn visit_expr_index(&mut self, expr: &'ast syn::ExprIndex) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_index(self, expr);
+    }
+
+    fn visit_expr_method_call(&mut self, expr: &'ast syn::ExprMethodCall) {
+        self.delete_elements(&expr.args);
+        self.replace_with_part(expr, &*expr.receiver);
+        visit::visit_expr_method_call(self, expr);
+    }
+
+    fn visit_expr_paren(&mut self, expr: &'ast syn::ExprParen) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_paren(self, expr);
+    }
+
+    fn visit_expr_reference(&mut self, expr: &'ast syn::ExprReference) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_reference(self, expr);
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast syn::ExprStruct) {
+        self.delete_elements(&expr.fields);
+        visit::visit_expr_struct(self, expr);
+    }
+
+    fn visit_expr_try(&mut self, expr: &'ast syn::ExprTry) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_try(self, expr);
+    }
+
+    fn visit_expr_tuple(&mut self, expr: &'ast syn::ExprTuple) {
+        self.delete_elements(&expr.elems);
+        visit::visit_expr_tuple(self, expr);
+    }
+
+    fn visit_expr_unary(&mut self, expr: &'ast syn::ExprUnary) {
+        self.replace_with_part(expr, &*expr.expr);
+        visit::visit_expr_unary(self, expr);
+    }
+}
+
+/// The edits which could make a file shorter, the largest first.
+fn edits(text: &str) -> syn::Result<Vec<Edit>> {
+    let file = syn::parse_file(text)?;
+    let mut collector = EditCollector {
+        text,
+        line_starts: line_starts(text),
+        edits: Vec::new(),
+    };
+    collector.visit_file(&file);
+    let mut edits = collector.edits;
+    edits.retain(|edit| edit.removed() > 0);
+    edits.sort_by(|a, b| {
+        b.removed()
+            .cmp(&a.removed())
+            .then(a.range.start.cmp(&b.range.start))
+            .then(a.replacement.cmp(&b.replacement))
+    });
+    edits.dedup();
+    Ok(edits)
+}
+
+/// The edits emptying the lines which only contain whitespace, and deleting them after another
+/// blank line, at the start or the end of a block, or at the start or the end of the text.
+fn blank_line_edits(text: &str) -> Vec<Edit> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let is_blank = |line: &str| line.trim().is_empty();
+    let mut edits = Vec::new();
+    let mut start = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if is_blank(line) {
+            let preceding = i.checked_sub(1).map(|i| lines[i].trim());
+            let following = lines[i + 1..]
+                .iter()
+                .map(|line| line.trim())
+                .find(|line| !line.is_empty());
+            let delete = preceding.map_or(true, |preceding| preceding.is_empty() || preceding.ends_with('{'))
+                || following.map_or(true, |following| following.starts_with('}'));
+            let end = if delete {
+                start + line.len()
+            } else {
+                start + line.trim_end_matches('\n').len()
+            };
+            if end > start {
+                edits.push(Edit {
+                    range: start..end,
+                    replacement: String::new(),
+                });
+            }
+        }
+        start += line.len();
+    }
+    edits
+}
+
+fn build_clippy() {
+    let status = Command::new("cargo")
+        .arg("build")
+        .current_dir(clippy_project_root())
+        .status()
+        .expect("failed to run cargo build");
+    if !status.success() {
+        eprintln!("error: failed to build Clippy");
+        process::exit(1);
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    let entries = WalkDir::new(from)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "target" && entry.file_name() != ".git");
+    for entry in entries {
+        let entry = entry?;
+        let copy = to.join(entry.path().strip_prefix(from).unwrap());
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(copy)?;
+        } else {
+            fs::copy(entry.path(), copy)?;
+        }
+    }
+    Ok(())
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+/// Copies the crate or the file at `path` to `dir`, to be reduced there.
+fn copy_input(path: &Path, dir: &Path) -> Target {
+    if let Err(error) = fs::remove_dir_all(&dir) {
+        assert!(
+            error.kind() == io::ErrorKind::NotFound,
+            "failed to remove {}: {}",
+            dir.display(),
+            error
+        );
+    }
+    if path.is_dir() {
+        if !path.join("Cargo.toml").is_file() {
+            fail(&format!("`{}` is not a crate", path.display()));
+        }
+        copy_dir(path, &dir).unwrap();
+        Target::Crate
+    } else {
+        let text = fs::read_to_string(path).unwrap_or_else(|error| fail(&format!("{}: {}", path.display(), error)));
+        let has_main = syn::parse_file(&text)
+            .unwrap_or_else(|error| fail(&format!("failed to parse {}: {}", path.display(), error)))
+            .items
+            .iter()
+            .any(|item| matches!(item, syn::Item::Fn(item) if item.sig.ident == "main"));
+        let file = PathBuf::from(path.file_name().unwrap());
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(&file), text).unwrap();
+        Target::File {
+            file,
+            crate_type: if has_main { "bin" } else { "lib" },
+        }
+    }
+}
+
+/// Parses a location of the form `[FILE:]LINE`.
+fn parse_location(location: &str) -> Option<(Option<PathBuf>, usize)> {
+    match location.rfind(':') {
+        Some(colon) => Some((
+            Some(PathBuf::from(&location[..colon])),
+            location[colon + 1..].parse().ok()?,
+        )),
+        None => Some((None, location.parse().ok()?)),
+    }
+}
+
+/// Reduces the crate or the file at `path`, keeping Clippy crashing the same way, or emitting
+/// `lint` at the location `at` if any. The copy being reduced is kept in `target/reduce`.
+///
+/// # Panics
+///
+/// Panics if the copy could not be written, or Clippy could not be run on it.
+pub fn run(path: &str, lint: Option<&str>, at: Option<&str>, output: Option<&str>, clippy_args: Vec<String>) {
+    let path = fs::canonicalize(path).unwrap_or_else(|error| fail(&format!("{}: {}", path, error)));
+    let path = path.as_path();
+    let dir = clippy_project_root()
+        .join("target")
+        .join("reduce")
+        .join(path.file_stem().unwrap());
+    let target = copy_input(path, &dir);
+
+    let predicate = match lint {
+        Some(lint) => Predicate::Lint(format!(
+            "clippy::{}",
+            lint.trim_start_matches("clippy::").replace('-', "_")
+        )),
+        None => Predicate::Ice,
+    };
+    let marker = at.map(|at| {
+        let (file, line_number) = parse_location(at).unwrap_or_else(|| fail(&format!("invalid location `{}`", at)));
+        let file = match (&target, file) {
+            (Target::File { file, .. }, _) => file.clone(),
+            (Target::Crate, Some(file)) => file,
+            (Target::Crate, None) => fail(&format!("the location `{}` needs a file in a crate", at)),
+        };
+        let text =
+            fs::read_to_string(dir.join(&file)).unwrap_or_else(|error| fail(&format!("{}: {}", file.display(), error)));
+        Marker::new(file, &text, line_number).unwrap_or_else(|| fail(&format!("there is no line {}", line_number)))
+    });
+
+    println!("Compiling clippy...");
+    build_clippy();
+
+    let mut reducer = Reducer {
+        dir,
+        target,
+        predicate,
+        marker,
+        ice: None,
+        errors: 0,
+        clippy_args,
+        runs: 0,
+    };
+    let outcome = reducer.run_clippy();
+    reducer.ice = outcome.ice.clone();
+    reducer.errors = outcome.errors;
+    if !reducer.holds(&outcome, reducer.marker.as_ref()) {
+        match &reducer.predicate {
+            Predicate::Ice => fail(&format!("Clippy doesn't crash on `{}`", path.display())),
+            Predicate::Lint(code) => fail(&format!("`{}` is not emitted there in `{}`", code, path.display())),
+        }
+    }
+
+    reducer.reduce();
+
+    let result = match &reducer.target {
+        Target::File { file, .. } => reducer.dir.join(file),
+        Target::Crate => reducer.dir.clone(),
+    };
+    let result = match output {
+        Some(output) => {
+            let output = PathBuf::from(output);
+            if result.is_dir() {
+                copy_dir(&result, &output).unwrap();
+            } else {
+                fs::copy(&result, &output).unwrap();
+            }
+            output
+        },
+        None => result,
+    };
+    println!("Reduced in {} runs of Clippy: {}", reducer.runs, result.display());
+    match reducer.predicate {
+        Predicate::Ice => println!("Once fixed, the crash can be tested in `tests/ui/crashes`"),
+        Predicate::Lint(_) => println!("The reproducer can be added to the tests of the lint in `tests/ui`"),
+    }
+}
+
+#[test]
+fn test_edits() {
+    let text = "fn main() {\n    let x = (1 + 2);\n    foo(x, 3);\n}\n";
+    let edited: Vec<String> = edits(text).unwrap().iter().map(|edit| edit.apply(text)).collect();
+
+    assert_eq!(edited[0], "\n");
+    assert!(edited.contains(&"fn main() {\n    \n    foo(x, 3);\n}\n".to_string()));
+    assert!(edited.contains(&"fn main() {\n    let x = (1 + 2);\n    foo(3);\n}\n".to_string()));
+    assert!(edited.contains(&"fn main() {\n    let x = (1 + 2);\n    foo(x, );\n}\n".to_string()));
+    assert!(edited.contains(&"fn main() {\n    let x = 1 + 2;\n    foo(x, 3);\n}\n".to_string()));
+    assert!(edited.contains(&"fn main() {\n    let x = (1);\n    foo(x, 3);\n}\n".to_string()));
+}
+
+#[test]
+fn test_marker() {
+    let text = "fn a() {}\nfn b() { c(1 + 2) }\nfn d() {}\n";
+    let marker = Marker::new(PathBuf::from("lib.rs"), text, 2).unwrap();
+    assert_eq!(&text[marker.range.clone()], "fn b() { c(1 + 2) }");
+
+    let before = Edit {
+        range: 0..10,
+        replacement: String::new(),
+    };
+    let moved = marker.after(&before).unwrap();
+    let edited = before.apply(text);
+    assert_eq!(moved.line(&edited), 1);
+    assert_eq!(&edited[moved.range], "fn b() { c(1 + 2) }");
+
+    let inside = Edit {
+        range: 21..26,
+        replacement: "1".to_string(),
+    };
+    let shrunk = marker.after(&inside).unwrap();
+    assert_eq!(&inside.apply(text)[shrunk.range], "fn b() { c(1) }");
+
+    let after = Edit {
+        range: 31..40,
+        replacement: String::new(),
+    };
+    assert_eq!(marker.after(&after), Some(marker.clone()));
+
+    let across = Edit {
+        range: 5..15,
+        replacement: String::new(),
+    };
+    assert_eq!(marker.after(&across), None);
+}
+
+#[test]
+fn test_blank_line_edits() {
+    let text = "fn a() {\n    \n}\n\n    \n\nfn b() {\n    c();\n\n    d();\n}\n\n";
+    let mut tidied = text.to_string();
+    for edit in blank_line_edits(text).iter().rev() {
+        tidied = edit.apply(&tidied);
+    }
+    assert_eq!(tidied, "fn a() {\n}\n\nfn b() {\n    c();\n\n    d();\n}\n");
+}
+
+#[test]
+fn test_parse_location() {
+    assert_eq!(parse_location("12"), Some((None, 12)));
+    assert_eq!(
+        parse_location("src/lib.rs:3"),
+        Some((Some(PathBuf::from("src/lib.rs")), 3))
+    );
+    assert_eq!(parse_location("src/lib.rs"), None);
+}
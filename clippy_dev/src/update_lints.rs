@@ -1,6 +1,6 @@
 use crate::{
-    gather_all, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_modules_list, gen_register_lint_list,
-    replace_region_in_file, Lint, DOCS_LINK,
+    gather_all, gather_lint_passes, gen_changelog_lint_list, gen_deprecated, gen_lint_group_list, gen_modules_list,
+    gen_pass_lints, gen_register_lint_list, replace_region_in_file, Lint, LintPass, DOCS_LINK,
 };
 use std::path::Path;
 
@@ -82,6 +82,20 @@ pub fn run(update_mode: UpdateMode) {
     )
     .changed;
 
+    let lint_passes: Vec<LintPass> = gather_lint_passes().collect();
+    file_change |= replace_region_in_file(
+        Path::new("clippy_lints/src/lib.rs"),
+        "begin pass lints",
+        "end pass lints",
+        false,
+        update_mode == UpdateMode::Change,
+        || {
+            let lints: Vec<&Lint> = internal_lints.iter().chain(usable_lints.iter()).collect();
+            gen_pass_lints(lint_passes.iter(), &lints)
+        },
+    )
+    .changed;
+
     // Generate lists of lints in the clippy::all lint group
     file_change |= replace_region_in_file(
         Path::new("clippy_lints/src/lib.rs"),
@@ -1,9 +1,12 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::disallowed_paths::DisallowedPaths;
 use crate::utils::{match_def_path, paths, span_lint_and_note};
+use rustc_ast::ast::Attribute;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{AsyncGeneratorKind, Body, BodyId, GeneratorKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::GeneratorInteriorTypeCause;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::Span;
 
 declare_clippy_lint! {
@@ -88,9 +91,85 @@ declare_clippy_lint! {
     "Inside an async function, holding a RefCell ref while calling await"
 }
 
-declare_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to await while holding a value of one of the types
+    /// configured in clippy.toml with `await-holding-invalid-types`.
+    ///
+    /// **Why is this bad?** Some values must not live across an `await` suspension point, like
+    /// database transactions or the guards of synchronous critical sections, as the task may be
+    /// suspended for an unbounded time while holding them.
+    ///
+    /// Each type is written as a path through which it can be imported, or as a table giving
+    /// the reason it must not be held, which is shown in the diagnostic.
+    ///
+    /// **Known problems:** Will report false positive for explicitly dropped values ([#6446](https://github.com/rust-lang/rust-clippy/issues/6446)).
+    /// The types of the crate being linted can only be configured by their definition path.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// await-holding-invalid-types = [
+    ///     { path = "sqlx::Transaction", reason = "commit the transaction before awaiting" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// async fn foo(pool: &Pool) {
+    ///   let transaction = pool.begin().await?;
+    ///   bar.await;
+    ///   transaction.commit().await?;
+    /// }
+    /// ```
+    pub AWAIT_HOLDING_INVALID_TYPE,
+    style,
+    "Inside an async function, holding a value of a type configured in clippy.toml while calling await"
+}
+
+#[derive(Clone, Debug)]
+pub struct AwaitHolding {
+    invalid_types: DisallowedPaths,
+}
 
-impl LateLintPass<'_> for AwaitHolding {
+impl AwaitHolding {
+    pub fn new(conf_invalid_types: &[DisallowedPath]) -> Self {
+        Self {
+            invalid_types: DisallowedPaths::new("await_holding_invalid_types", conf_invalid_types),
+        }
+    }
+
+    fn check_invalid_types(&self, cx: &LateContext<'_>, ty_causes: &[GeneratorInteriorTypeCause<'_>], span: Span) {
+        for ty_cause in ty_causes {
+            if let rustc_middle::ty::Adt(adt, _) = ty_cause.ty.kind() {
+                if let Some(invalid_type) = self.invalid_types.get(cx, adt.did) {
+                    invalid_type.lint_and_then(
+                        cx,
+                        AWAIT_HOLDING_INVALID_TYPE,
+                        ty_cause.span,
+                        adt.did,
+                        |path| {
+                            format!(
+                                "this `{}` is held across an 'await' point, which is not allowed by the configuration",
+                                path
+                            )
+                        },
+                        |diag| {
+                            diag.span_note(
+                                ty_cause.scope_span.unwrap_or(span),
+                                "these are all the await points this value is held through",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF, AWAIT_HOLDING_INVALID_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for AwaitHolding {
     fn check_body(&mut self, cx: &LateContext<'_>, body: &'_ Body<'_>) {
         use AsyncGeneratorKind::{Block, Closure, Fn};
         if let Some(GeneratorKind::Async(Block | Closure | Fn)) = body.generator_kind {
@@ -99,13 +178,21 @@ impl LateLintPass<'_> for AwaitHolding {
             };
             let def_id = cx.tcx.hir().body_owner_def_id(body_id);
             let typeck_results = cx.tcx.typeck(def_id);
-            check_interior_types(
-                cx,
-                &typeck_results.generator_interior_types.as_ref().skip_binder(),
-                body.value.span,
-            );
+            let ty_causes = typeck_results.generator_interior_types.as_ref().skip_binder();
+            check_interior_types(cx, ty_causes, body.value.span);
+            if !self.invalid_types.is_empty() {
+                self.check_invalid_types(cx, ty_causes, body.value.span);
+            }
         }
     }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.invalid_types.push_attrs(cx, attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.invalid_types.pop_attrs(cx, attrs);
+    }
 }
 
 fn check_interior_types(cx: &LateContext<'_>, ty_causes: &[GeneratorInteriorTypeCause<'_>], span: Span) {
@@ -1,6 +1,6 @@
 use crate::consts::{constant, Constant};
 use crate::utils::sugg::Sugg;
-use crate::utils::{span_lint, span_lint_and_then};
+use crate::utils::{span_lint, span_lint_and_then, ConfStack};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -94,16 +94,16 @@ declare_clippy_lint! {
     "expressions where a bit mask is less readable than the corresponding method call"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct BitMask {
-    verbose_bit_mask_threshold: u64,
+    verbose_bit_mask_threshold: ConfStack<u64>,
 }
 
 impl BitMask {
     #[must_use]
     pub fn new(verbose_bit_mask_threshold: u64) -> Self {
         Self {
-            verbose_bit_mask_threshold,
+            verbose_bit_mask_threshold: ConfStack::new("verbose_bit_mask_threshold", verbose_bit_mask_threshold),
         }
     }
 }
@@ -131,7 +131,7 @@ impl<'tcx> LateLintPass<'tcx> for BitMask {
             if let ExprKind::Lit(lit1) = &right.kind;
             if let LitKind::Int(0, _) = lit1.node;
             if n.leading_zeros() == n.count_zeros();
-            if n > u128::from(self.verbose_bit_mask_threshold);
+            if n > u128::from(*self.verbose_bit_mask_threshold.current());
             then {
                 span_lint_and_then(cx,
                                    VERBOSE_BIT_MASK,
@@ -149,6 +149,8 @@ impl<'tcx> LateLintPass<'tcx> for BitMask {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, verbose_bit_mask_threshold);
 }
 
 #[must_use]
@@ -1,4 +1,4 @@
-use crate::utils::span_lint;
+use crate::utils::{span_lint, ConfStack};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::{Pat, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -24,12 +24,14 @@ declare_clippy_lint! {
 
 #[derive(Clone, Debug)]
 pub struct BlacklistedName {
-    blacklist: FxHashSet<String>,
+    blacklist: ConfStack<FxHashSet<String>>,
 }
 
 impl BlacklistedName {
     pub fn new(blacklist: FxHashSet<String>) -> Self {
-        Self { blacklist }
+        Self {
+            blacklist: ConfStack::new("blacklisted_names", blacklist),
+        }
     }
 }
 
@@ -38,7 +40,7 @@ impl_lint_pass!(BlacklistedName => [BLACKLISTED_NAME]);
 impl<'tcx> LateLintPass<'tcx> for BlacklistedName {
     fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
         if let PatKind::Binding(.., ident, _) = pat.kind {
-            if self.blacklist.contains(&ident.name.to_string()) {
+            if self.blacklist.current().contains(&ident.name.to_string()) {
                 span_lint(
                     cx,
                     BLACKLISTED_NAME,
@@ -48,4 +50,6 @@ impl<'tcx> LateLintPass<'tcx> for BlacklistedName {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, blacklist);
 }
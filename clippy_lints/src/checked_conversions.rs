@@ -9,9 +9,7 @@ use rustc_middle::lint::in_external_macro;
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 
-use crate::utils::{meets_msrv, snippet_with_applicability, span_lint_and_sugg, SpanlessEq};
-
-const CHECKED_CONVERSIONS_MSRV: RustcVersion = RustcVersion::new(1, 34, 0);
+use crate::utils::{paths, snippet_with_applicability, span_lint_and_sugg, MsrvStack, SpanlessEq, StdItem};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for explicit bounds checking when casting.
@@ -43,13 +41,17 @@ declare_clippy_lint! {
 }
 
 pub struct CheckedConversions {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
+    try_from: StdItem,
 }
 
 impl CheckedConversions {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+            try_from: StdItem::new(&paths::TRY_FROM),
+        }
     }
 }
 
@@ -57,7 +59,7 @@ impl_lint_pass!(CheckedConversions => [CHECKED_CONVERSIONS]);
 
 impl<'tcx> LateLintPass<'tcx> for CheckedConversions {
     fn check_expr(&mut self, cx: &LateContext<'_>, item: &Expr<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &CHECKED_CONVERSIONS_MSRV) {
+        if !self.try_from.meets_msrv(cx, self.msrv.current()) {
             return;
         }
 
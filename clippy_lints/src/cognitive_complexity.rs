@@ -130,8 +130,12 @@ impl<'tcx> LateLintPass<'tcx> for CognitiveComplexity {
 
     fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
         self.limit.push_attrs(cx.sess(), attrs, "cognitive_complexity");
+        self.limit
+            .push_conf_attrs(cx.sess(), attrs, "cognitive_complexity_threshold");
     }
     fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit
+            .pop_conf_attrs(cx.sess(), attrs, "cognitive_complexity_threshold");
         self.limit.pop_attrs(cx.sess(), attrs, "cognitive_complexity");
     }
 }
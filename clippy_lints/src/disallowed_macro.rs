@@ -0,0 +1,134 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::disallowed_paths::DisallowedPaths;
+
+use rustc_ast::ast::Attribute;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Expr, ImplItem, Item, Pat, Stmt, TraitItem, Ty};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::hygiene::{ExpnId, SyntaxContext};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Denies the configured macros in clippy.toml.
+    ///
+    /// **Why is this bad?** Some macros are undesirable in certain contexts, like `println!`
+    /// replaced by a logging framework.
+    ///
+    /// Each macro is written as a path through which it can be imported, like `std::println`,
+    /// or as a table giving the reason it is disallowed, which is shown in the diagnostic. The
+    /// invocations nested in other macros, like `dbg!` in `vec![dbg!(x)]` or in the definition
+    /// of a local macro, are linted too.
+    ///
+    /// **Known problems:** Invocations expanding to nothing, and invocations in the definition
+    /// of macros from other crates, are not linted. The macros of the crate being linted can
+    /// only be disallowed by their definition path.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-macros = [
+    ///     "std::println",
+    ///     { path = "std::dbg", reason = "use `tracing::debug!`" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// println!("starting");
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// tracing::info!("starting");
+    /// ```
+    pub DISALLOWED_MACRO,
+    style,
+    "use of a disallowed macro"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMacro {
+    disallowed: DisallowedPaths,
+    /// The expansions already checked, as many nodes come from the same one.
+    seen: FxHashSet<ExpnId>,
+    /// The invocations already linted, without their context, so that an invocation in the
+    /// definition of a macro is linted once rather than for each use of the macro.
+    linted: FxHashSet<Span>,
+}
+
+impl DisallowedMacro {
+    pub fn new(conf_disallowed: &[DisallowedPath]) -> Self {
+        Self {
+            disallowed: DisallowedPaths::new("disallowed_macros", conf_disallowed),
+            seen: FxHashSet::default(),
+            linted: FxHashSet::default(),
+        }
+    }
+
+    fn check_span(&mut self, cx: &LateContext<'_>, mut span: Span) {
+        if self.disallowed.is_empty() {
+            return;
+        }
+        while span.from_expansion() {
+            let expn = span.ctxt().outer_expn();
+            // the outer invocations of a checked expansion are checked too
+            if !self.seen.insert(expn) {
+                return;
+            }
+            let data = expn.expn_data();
+            span = data.call_site;
+            let def_id = match data.macro_def_id {
+                Some(def_id) if !in_external_macro(cx.sess(), span) => def_id,
+                _ => continue,
+            };
+            let disallowed = match self.disallowed.get(cx, def_id) {
+                Some(disallowed) if self.linted.insert(span.with_ctxt(SyntaxContext::root())) => disallowed,
+                _ => continue,
+            };
+            disallowed.lint(cx, DISALLOWED_MACRO, span, "macro", def_id);
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMacro => [DISALLOWED_MACRO]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedMacro {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        self.check_span(cx, expr.span);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        self.check_span(cx, stmt.span);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        self.check_span(cx, item.span);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        self.check_span(cx, item.span);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        self.check_span(cx, item.span);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'_>) {
+        self.check_span(cx, ty.span);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
+        self.check_span(cx, pat.span);
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.push_attrs(cx, attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.pop_attrs(cx, attrs);
+    }
+}
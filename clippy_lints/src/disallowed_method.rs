@@ -1,10 +1,13 @@
-use crate::utils::{fn_def_id, span_lint};
+use crate::utils::conf::DisallowedPath;
+use crate::utils::disallowed_paths::DisallowedPaths;
+use crate::utils::fn_def_id;
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_ast::ast::Attribute;
+use rustc_hir::def_id::DefId;
 use rustc_hir::Expr;
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::AssocItemContainer;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::Symbol;
 
 declare_clippy_lint! {
     /// **What it does:** Denies the configured methods and functions in clippy.toml
@@ -12,21 +15,26 @@ declare_clippy_lint! {
     /// **Why is this bad?** Some methods are undesirable in certain contexts,
     /// and it's beneficial to lint for them as needed.
     ///
-    /// **Known problems:** Currently, you must write each function as a
-    /// fully-qualified path. This lint doesn't support aliases or reexported
-    /// names; be aware that many types in `std` are actually reexports.
+    /// Each method is written as a path through which it can be imported, like
+    /// `std::time::Duration::as_secs`, or as a table giving the reason it is disallowed, which
+    /// is shown in the diagnostic. A path ending with `::*` disallows all the functions and
+    /// methods under a module, a type or a trait. Disallowing a trait method also disallows its
+    /// implementations, including when it is called through a generic bound.
     ///
-    /// For example, if you want to disallow `Duration::as_secs`, your clippy.toml
-    /// configuration would look like
-    /// `disallowed-methods = ["core::time::Duration::as_secs"]` and not
-    /// `disallowed-methods = ["std::time::Duration::as_secs"]` as you might expect.
+    /// **Known problems:** The items of the crate being linted, and the items of other crates
+    /// in private modules, can only be disallowed by their definition path, like
+    /// `regex::re_unicode::Regex::new`.
     ///
     /// **Example:**
     ///
     /// An example clippy.toml configuration:
     /// ```toml
     /// # clippy.toml
-    /// disallowed-methods = ["alloc::vec::Vec::leak", "std::time::Instant::now"]
+    /// disallowed-methods = [
+    ///     "std::vec::Vec::leak",
+    ///     { path = "std::time::Instant::now", reason = "use our clock abstraction" },
+    ///     "std::env::*",
+    /// ]
     /// ```
     ///
     /// ```rust,ignore
@@ -44,46 +52,57 @@ declare_clippy_lint! {
     /// xs.push(123); // Vec::push is _not_ disallowed in the config.
     /// ```
     pub DISALLOWED_METHOD,
-    nursery,
+    style,
     "use of a disallowed method call"
 }
 
 #[derive(Clone, Debug)]
 pub struct DisallowedMethod {
-    disallowed: FxHashSet<Vec<Symbol>>,
+    disallowed: DisallowedPaths,
 }
 
 impl DisallowedMethod {
-    pub fn new(disallowed: &FxHashSet<String>) -> Self {
+    pub fn new(conf_disallowed: &[DisallowedPath]) -> Self {
         Self {
-            disallowed: disallowed
-                .iter()
-                .map(|s| s.split("::").map(|seg| Symbol::intern(seg)).collect::<Vec<_>>())
-                .collect(),
+            disallowed: DisallowedPaths::new("disallowed_methods", conf_disallowed).with_globs(),
         }
     }
 }
 
+/// The trait method implemented by the impl method `def_id`, if it is one.
+fn trait_item(cx: &LateContext<'_>, def_id: DefId) -> Option<DefId> {
+    let item = cx.tcx.opt_associated_item(def_id)?;
+    match item.container {
+        AssocItemContainer::ImplContainer(impl_id) => cx
+            .tcx
+            .associated_items(cx.tcx.trait_id_of_impl(impl_id)?)
+            .filter_by_name_unhygienic(item.ident.name)
+            .next()
+            .map(|trait_item| trait_item.def_id),
+        AssocItemContainer::TraitContainer(_) => None,
+    }
+}
+
 impl_lint_pass!(DisallowedMethod => [DISALLOWED_METHOD]);
 
 impl<'tcx> LateLintPass<'tcx> for DisallowedMethod {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some(def_id) = fn_def_id(cx, expr) {
-            let func_path = cx.get_def_path(def_id);
-            if self.disallowed.contains(&func_path) {
-                let func_path_string = func_path
-                    .into_iter()
-                    .map(Symbol::to_ident_string)
-                    .collect::<Vec<_>>()
-                    .join("::");
-
-                span_lint(
-                    cx,
-                    DISALLOWED_METHOD,
-                    expr.span,
-                    &format!("use of a disallowed method `{}`", func_path_string),
-                );
+            let disallowed = Some(def_id)
+                .into_iter()
+                .chain(trait_item(cx, def_id))
+                .find_map(|def_id| self.disallowed.get(cx, def_id));
+            if let Some(disallowed) = disallowed {
+                disallowed.lint(cx, DISALLOWED_METHOD, expr.span, "method", def_id);
             }
         }
     }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.push_attrs(cx, attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.pop_attrs(cx, attrs);
+    }
 }
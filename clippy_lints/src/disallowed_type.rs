@@ -0,0 +1,129 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::disallowed_paths::DisallowedPaths;
+
+use rustc_ast::ast::Attribute;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Item, ItemKind, QPath, Ty, TyKind, UseKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::DefIdTree;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Denies the configured types in clippy.toml.
+    ///
+    /// **Why is this bad?** Some types are undesirable in certain contexts, like a standard type
+    /// replaced by a project-wide alternative.
+    ///
+    /// Each type is written as a path through which it can be imported, like
+    /// `std::collections::HashMap`, or as a table giving the reason it is disallowed, which is
+    /// shown in the diagnostic. Every mention of the type is linted: in `use` statements,
+    /// signatures, fields, casts, and expressions like `HashMap::new()` or struct literals.
+    ///
+    /// **Known problems:** The items of the crate being linted, and the items of other crates
+    /// in private modules, can only be disallowed by their definition path. Type aliases of a
+    /// disallowed type are only linted where they are defined.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-types = [
+    ///     "std::collections::HashMap",
+    ///     { path = "std::sync::Mutex", reason = "use `parking_lot::Mutex`" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use crate::collections::FxHashMap;
+    ///
+    /// let map: FxHashMap<u32, u32> = FxHashMap::default();
+    /// ```
+    pub DISALLOWED_TYPE,
+    style,
+    "use of a disallowed type"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedType {
+    disallowed: DisallowedPaths,
+}
+
+impl DisallowedType {
+    pub fn new(conf_disallowed: &[DisallowedPath]) -> Self {
+        Self {
+            disallowed: DisallowedPaths::new("disallowed_types", conf_disallowed),
+        }
+    }
+
+    fn check_res(&self, cx: &LateContext<'_>, res: Res, span: Span) {
+        let def_id = match type_of_res(cx, res) {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        if in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let Some(disallowed) = self.disallowed.get(cx, def_id) {
+            disallowed.lint(cx, DISALLOWED_TYPE, span, "type", def_id);
+        }
+    }
+}
+
+fn type_of_res(cx: &LateContext<'_>, res: Res) -> Option<DefId> {
+    match res {
+        Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias | DefKind::ForeignTy, def_id) => {
+            Some(def_id)
+        },
+        Res::Def(DefKind::Ctor(CtorOf::Struct, _) | DefKind::Variant, def_id) => cx.tcx.parent(def_id),
+        Res::Def(DefKind::Ctor(CtorOf::Variant, _), def_id) => cx.tcx.parent(cx.tcx.parent(def_id)?),
+        _ => None,
+    }
+}
+
+impl_lint_pass!(DisallowedType => [DISALLOWED_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedType {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Use(path, UseKind::Single) = item.kind {
+            self.check_res(cx, path.res, item.span);
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'_>) {
+        if let TyKind::Path(qpath) = &ty.kind {
+            self.check_res(cx, cx.qpath_res(qpath, ty.hir_id), ty.span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // the types of paths like `HashMap::new` are checked by `check_ty`
+        match &expr.kind {
+            ExprKind::Path(qpath @ QPath::Resolved(None, _)) => {
+                self.check_res(cx, cx.qpath_res(qpath, expr.hir_id), expr.span);
+            },
+            ExprKind::Struct(qpath @ QPath::Resolved(None, _), ..) => {
+                self.check_res(cx, cx.qpath_res(qpath, expr.hir_id), qpath.span());
+            },
+            _ => {},
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.push_attrs(cx, attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.disallowed.pop_attrs(cx, attrs);
+    }
+}
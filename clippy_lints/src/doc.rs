@@ -1,6 +1,6 @@
 use crate::utils::{
     implements_trait, is_entrypoint_fn, is_expn_of, is_type_diagnostic_item, match_panic_def_id, method_chain_args,
-    return_ty, span_lint, span_lint_and_note,
+    return_ty, span_lint, span_lint_and_note, ConfStack,
 };
 use if_chain::if_chain;
 use itertools::Itertools;
@@ -190,14 +190,14 @@ declare_clippy_lint! {
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct DocMarkdown {
-    valid_idents: FxHashSet<String>,
+    valid_idents: ConfStack<FxHashSet<String>>,
     in_trait_impl: bool,
 }
 
 impl DocMarkdown {
     pub fn new(valid_idents: FxHashSet<String>) -> Self {
         Self {
-            valid_idents,
+            valid_idents: ConfStack::new("doc_valid_idents", valid_idents),
             in_trait_impl: false,
         }
     }
@@ -209,11 +209,11 @@ impl_lint_pass!(DocMarkdown =>
 
 impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx hir::Crate<'_>) {
-        check_attrs(cx, &self.valid_idents, &krate.item.attrs);
+        check_attrs(cx, self.valid_idents.current(), &krate.item.attrs);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
-        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        let headers = check_attrs(cx, self.valid_idents.current(), &item.attrs);
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, cx.tcx.hir().local_def_id(item.hir_id).to_def_id())
@@ -244,7 +244,7 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
-        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        let headers = check_attrs(cx, self.valid_idents.current(), &item.attrs);
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
             if !in_external_macro(cx.tcx.sess, item.span) {
                 lint_for_missing_headers(cx, item.hir_id, item.span, sig, headers, None, None);
@@ -253,7 +253,7 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
-        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        let headers = check_attrs(cx, self.valid_idents.current(), &item.attrs);
         if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) {
             return;
         }
@@ -269,6 +269,8 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
             lint_for_missing_headers(cx, item.hir_id, item.span, sig, headers, Some(body_id), fpu.panic_span);
         }
     }
+
+    extract_conf_attrs!(LateContext, valid_idents);
 }
 
 fn lint_for_missing_headers<'tcx>(
@@ -1,7 +1,7 @@
 //! lint on enum variants that are prefixed or suffixed by the same characters
 
 use crate::utils::{camel_case, is_present_in_source};
-use crate::utils::{span_lint, span_lint_and_help};
+use crate::utils::{span_lint, span_lint_and_help, ConfStack};
 use rustc_ast::ast::{EnumDef, Item, ItemKind, VisibilityKind};
 use rustc_lint::{EarlyContext, EarlyLintPass, Lint};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -125,7 +125,7 @@ declare_clippy_lint! {
 
 pub struct EnumVariantNames {
     modules: Vec<(Symbol, String)>,
-    threshold: u64,
+    threshold: ConfStack<u64>,
 }
 
 impl EnumVariantNames {
@@ -133,7 +133,7 @@ impl EnumVariantNames {
     pub fn new(threshold: u64) -> Self {
         Self {
             modules: Vec::new(),
-            threshold,
+            threshold: ConfStack::new("enum_variant_name_threshold", threshold),
         }
     }
 }
@@ -320,8 +320,18 @@ impl EarlyLintPass for EnumVariantNames {
                 VisibilityKind::Public => PUB_ENUM_VARIANT_NAMES,
                 _ => ENUM_VARIANT_NAMES,
             };
-            check_variant(cx, self.threshold, def, &item_name, item_name_chars, item.span, lint);
+            check_variant(
+                cx,
+                *self.threshold.current(),
+                def,
+                &item_name,
+                item_name_chars,
+                item.span,
+                lint,
+            );
         }
         self.modules.push((item.ident.name, item_camel));
     }
+
+    extract_conf_attrs!(EarlyContext, threshold);
 }
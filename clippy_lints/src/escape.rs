@@ -10,11 +10,20 @@ use rustc_target::abi::LayoutOf;
 use rustc_target::spec::abi::Abi;
 use rustc_typeck::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
 
-use crate::utils::{contains_ty, span_lint};
+use crate::utils::{contains_ty, span_lint, ConfStack};
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct BoxedLocal {
-    pub too_large_for_stack: u64,
+    too_large_for_stack: ConfStack<u64>,
+}
+
+impl BoxedLocal {
+    #[must_use]
+    pub fn new(too_large_for_stack: u64) -> Self {
+        Self {
+            too_large_for_stack: ConfStack::new("too_large_for_stack", too_large_for_stack),
+        }
+    }
 }
 
 declare_clippy_lint! {
@@ -102,7 +111,7 @@ impl<'tcx> LateLintPass<'tcx> for BoxedLocal {
             cx,
             set: HirIdSet::default(),
             trait_self_ty,
-            too_large_for_stack: self.too_large_for_stack,
+            too_large_for_stack: *self.too_large_for_stack.current(),
         };
 
         let fn_def_id = cx.tcx.hir().local_def_id(hir_id);
@@ -119,6 +128,8 @@ impl<'tcx> LateLintPass<'tcx> for BoxedLocal {
             );
         }
     }
+
+    extract_conf_attrs!(LateContext, too_large_for_stack);
 }
 
 // TODO: Replace with Map::is_argument(..) when it's fixed
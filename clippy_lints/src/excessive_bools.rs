@@ -1,4 +1,4 @@
-use crate::utils::{attr_by_name, in_macro, match_path_ast, span_lint_and_help};
+use crate::utils::{attr_by_name, in_macro, match_path_ast, span_lint_and_help, ConfStack};
 use rustc_ast::ast::{AssocItemKind, Extern, FnKind, FnSig, ImplKind, Item, ItemKind, TraitKind, Ty, TyKind};
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -81,16 +81,16 @@ declare_clippy_lint! {
 }
 
 pub struct ExcessiveBools {
-    max_struct_bools: u64,
-    max_fn_params_bools: u64,
+    max_struct_bools: ConfStack<u64>,
+    max_fn_params_bools: ConfStack<u64>,
 }
 
 impl ExcessiveBools {
     #[must_use]
     pub fn new(max_struct_bools: u64, max_fn_params_bools: u64) -> Self {
         Self {
-            max_struct_bools,
-            max_fn_params_bools,
+            max_struct_bools: ConfStack::new("max_struct_bools", max_struct_bools),
+            max_fn_params_bools: ConfStack::new("max_fn_params_bools", max_fn_params_bools),
         }
     }
 
@@ -108,12 +108,15 @@ impl ExcessiveBools {
             .count()
             .try_into()
             .unwrap();
-        if self.max_fn_params_bools < fn_sig_bools {
+        if *self.max_fn_params_bools.current() < fn_sig_bools {
             span_lint_and_help(
                 cx,
                 FN_PARAMS_EXCESSIVE_BOOLS,
                 span,
-                &format!("more than {} bools in function parameters", self.max_fn_params_bools),
+                &format!(
+                    "more than {} bools in function parameters",
+                    self.max_fn_params_bools.current()
+                ),
                 None,
                 "consider refactoring bools into two-variant enums",
             );
@@ -148,12 +151,12 @@ impl EarlyLintPass for ExcessiveBools {
                     .count()
                     .try_into()
                     .unwrap();
-                if self.max_struct_bools < struct_bools {
+                if *self.max_struct_bools.current() < struct_bools {
                     span_lint_and_help(
                         cx,
                         STRUCT_EXCESSIVE_BOOLS,
                         item.span,
-                        &format!("more than {} bools in a struct", self.max_struct_bools),
+                        &format!("more than {} bools in a struct", self.max_struct_bools.current()),
                         None,
                         "consider using a state machine or refactoring bools into two-variant enums",
                     );
@@ -173,4 +176,6 @@ impl EarlyLintPass for ExcessiveBools {
             _ => (),
         }
     }
+
+    extract_conf_attrs!(EarlyContext, max_struct_bools, max_fn_params_bools);
 }
@@ -1,5 +1,5 @@
 use crate::utils::paths::INTO;
-use crate::utils::{match_def_path, meets_msrv, span_lint_and_help};
+use crate::utils::{match_def_path, meets_msrv, span_lint_and_help, MsrvStack};
 use if_chain::if_chain;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
@@ -42,13 +42,15 @@ declare_clippy_lint! {
 }
 
 pub struct FromOverInto {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
 }
 
 impl FromOverInto {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        FromOverInto { msrv }
+        FromOverInto {
+            msrv: MsrvStack::new(msrv),
+        }
     }
 }
 
@@ -56,7 +58,7 @@ impl_lint_pass!(FromOverInto => [FROM_OVER_INTO]);
 
 impl LateLintPass<'_> for FromOverInto {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &FROM_OVER_INTO_MSRV) {
+        if !meets_msrv(self.msrv.current(), &FROM_OVER_INTO_MSRV) {
             return;
         }
 
@@ -1,7 +1,7 @@
 use crate::utils::{
     attr_by_name, attrs::is_proc_macro, is_must_use_ty, is_trait_impl_item, is_type_diagnostic_item, iter_input_pats,
     last_path_segment, match_def_path, must_use_attr, path_to_local, return_ty, snippet, snippet_opt, span_lint,
-    span_lint_and_help, span_lint_and_then, trait_ref_of_method, type_is_unsafe_function,
+    span_lint_and_help, span_lint_and_then, trait_ref_of_method, type_is_unsafe_function, ConfStack,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
@@ -218,15 +218,18 @@ declare_clippy_lint! {
     "public function returning `Result` with an `Err` type of `()`"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Functions {
-    threshold: u64,
-    max_lines: u64,
+    threshold: ConfStack<u64>,
+    max_lines: ConfStack<u64>,
 }
 
 impl Functions {
     pub fn new(threshold: u64, max_lines: u64) -> Self {
-        Self { threshold, max_lines }
+        Self {
+            threshold: ConfStack::new("too_many_arguments_threshold", threshold),
+            max_lines: ConfStack::new("too_many_lines_threshold", max_lines),
+        }
     }
 }
 
@@ -367,22 +370,25 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, threshold, max_lines);
 }
 
 impl<'tcx> Functions {
-    fn check_arg_number(self, cx: &LateContext<'_>, decl: &hir::FnDecl<'_>, fn_span: Span) {
+    fn check_arg_number(&self, cx: &LateContext<'_>, decl: &hir::FnDecl<'_>, fn_span: Span) {
         let args = decl.inputs.len() as u64;
-        if args > self.threshold {
+        let threshold = *self.threshold.current();
+        if args > threshold {
             span_lint(
                 cx,
                 TOO_MANY_ARGUMENTS,
                 fn_span,
-                &format!("this function has too many arguments ({}/{})", args, self.threshold),
+                &format!("this function has too many arguments ({}/{})", args, threshold),
             );
         }
     }
 
-    fn check_line_number(self, cx: &LateContext<'_>, span: Span, body: &'tcx hir::Body<'_>) {
+    fn check_line_number(&self, cx: &LateContext<'_>, span: Span, body: &'tcx hir::Body<'_>) {
         if in_external_macro(cx.sess(), span) {
             return;
         }
@@ -428,12 +434,13 @@ impl<'tcx> Functions {
             }
         }
 
-        if line_count > self.max_lines {
+        let max_lines = *self.max_lines.current();
+        if line_count > max_lines {
             span_lint(
                 cx,
                 TOO_MANY_LINES,
                 span,
-                &format!("this function has too many lines ({}/{})", line_count, self.max_lines),
+                &format!("this function has too many lines ({}/{})", line_count, max_lines),
             )
         }
     }
@@ -0,0 +1,98 @@
+use crate::utils::{meets_msrv, span_lint, stable_since, MsrvStack};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of standard library functions, methods, constants and
+    /// trait items which were stabilized in a more recent version of Rust than the configured
+    /// minimum supported Rust version (MSRV).
+    ///
+    /// **Why is this bad?** The code doesn't compile with the Rust versions it claims to support.
+    /// This lint catches it without needing an older toolchain.
+    ///
+    /// **Known problems:** Uses behind a `cfg` which depends on the version of the compiler are
+    /// linted as well. The MSRV of these items can be raised with the `#[clippy::msrv]` attribute.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // with `msrv = "1.40"` in `clippy.toml`
+    /// // `str::strip_prefix` is stable since Rust 1.45.0
+    /// let name = "clippy::msrv".strip_prefix("clippy::");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let path = "clippy::msrv";
+    /// let name = if path.starts_with("clippy::") {
+    ///     Some(&path["clippy::".len()..])
+    /// } else {
+    ///     None
+    /// };
+    /// ```
+    pub INCOMPATIBLE_MSRV,
+    nursery,
+    "use of standard library items stabilized after the configured MSRV"
+}
+
+pub struct IncompatibleMsrv {
+    msrv: MsrvStack,
+}
+
+impl IncompatibleMsrv {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+
+    fn check(&self, cx: &LateContext<'_>, def_id: DefId, span: Span) {
+        let msrv = match self.msrv.current() {
+            Some(msrv) => msrv,
+            None => return,
+        };
+        if def_id.is_local() || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let Some(since) = stable_since(cx, def_id) {
+            if !meets_msrv(Some(msrv), &since) {
+                span_lint(
+                    cx,
+                    INCOMPATIBLE_MSRV,
+                    span,
+                    &format!(
+                        "current MSRV (Minimum Supported Rust Version) is `{}` but this item is stable since `{}`",
+                        msrv, since
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl_lint_pass!(IncompatibleMsrv => [INCOMPATIBLE_MSRV]);
+
+impl<'tcx> LateLintPass<'tcx> for IncompatibleMsrv {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::MethodCall(path, ..) => {
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.check(cx, def_id, path.ident.span);
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() {
+                    self.check(cx, def_id, expr.span);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
@@ -1,5 +1,5 @@
 use crate::rustc_target::abi::LayoutOf;
-use crate::utils::span_lint_and_then;
+use crate::utils::{span_lint_and_then, ConfStack};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind};
@@ -33,13 +33,15 @@ declare_clippy_lint! {
 }
 
 pub struct LargeConstArrays {
-    maximum_allowed_size: u64,
+    maximum_allowed_size: ConfStack<u64>,
 }
 
 impl LargeConstArrays {
     #[must_use]
     pub fn new(maximum_allowed_size: u64) -> Self {
-        Self { maximum_allowed_size }
+        Self {
+            maximum_allowed_size: ConfStack::new("array_size_threshold", maximum_allowed_size),
+        }
     }
 }
 
@@ -55,7 +57,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeConstArrays {
             if let ConstKind::Value(ConstValue::Scalar(element_count)) = cst.val;
             if let Ok(element_count) = element_count.to_machine_usize(&cx.tcx);
             if let Ok(element_size) = cx.layout_of(element_type).map(|l| l.size.bytes());
-            if self.maximum_allowed_size < element_count * element_size;
+            if *self.maximum_allowed_size.current() < element_count * element_size;
 
             then {
                 let hi_pos = item.ident.span.lo() - BytePos::from_usize(1);
@@ -81,4 +83,6 @@ impl<'tcx> LateLintPass<'tcx> for LargeConstArrays {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, maximum_allowed_size);
 }
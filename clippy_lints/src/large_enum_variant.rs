@@ -1,6 +1,6 @@
 //! lint when there is a large size difference between variants on an enum
 
-use crate::utils::{snippet_opt, span_lint_and_then};
+use crate::utils::{snippet_opt, span_lint_and_then, ConfStack};
 use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind, VariantData};
 use rustc_lint::{LateContext, LateLintPass};
@@ -41,16 +41,19 @@ declare_clippy_lint! {
     "large size difference between variants on an enum"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct LargeEnumVariant {
-    maximum_size_difference_allowed: u64,
+    maximum_size_difference_allowed: ConfStack<u64>,
 }
 
 impl LargeEnumVariant {
     #[must_use]
     pub fn new(maximum_size_difference_allowed: u64) -> Self {
         Self {
-            maximum_size_difference_allowed,
+            maximum_size_difference_allowed: ConfStack::new(
+                "enum_variant_size_threshold",
+                maximum_size_difference_allowed,
+            ),
         }
     }
 }
@@ -93,7 +96,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeEnumVariant {
             if let (Some(largest), Some(second)) = (largest_variant, second_variant) {
                 let difference = largest.0 - second.0;
 
-                if difference > self.maximum_size_difference_allowed {
+                if difference > *self.maximum_size_difference_allowed.current() {
                     let (i, variant) = largest.1;
 
                     let help_text = "consider boxing the large fields to reduce the total size of the enum";
@@ -135,4 +138,6 @@ impl<'tcx> LateLintPass<'tcx> for LargeEnumVariant {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, maximum_size_difference_allowed);
 }
@@ -7,7 +7,7 @@ use rustc_session::{declare_tool_lint, impl_lint_pass};
 use if_chain::if_chain;
 
 use crate::rustc_target::abi::LayoutOf;
-use crate::utils::{snippet, span_lint_and_help};
+use crate::utils::{snippet, span_lint_and_help, ConfStack};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for local arrays that may be too large.
@@ -26,13 +26,15 @@ declare_clippy_lint! {
 }
 
 pub struct LargeStackArrays {
-    maximum_allowed_size: u64,
+    maximum_allowed_size: ConfStack<u64>,
 }
 
 impl LargeStackArrays {
     #[must_use]
     pub fn new(maximum_allowed_size: u64) -> Self {
-        Self { maximum_allowed_size }
+        Self {
+            maximum_allowed_size: ConfStack::new("array_size_threshold", maximum_allowed_size),
+        }
     }
 }
 
@@ -46,7 +48,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
             if let ConstKind::Value(ConstValue::Scalar(element_count)) = cst.val;
             if let Ok(element_count) = element_count.to_machine_usize(&cx.tcx);
             if let Ok(element_size) = cx.layout_of(element_type).map(|l| l.size.bytes());
-            if self.maximum_allowed_size < element_count * element_size;
+            if *self.maximum_allowed_size.current() < element_count * element_size;
             then {
                 span_lint_and_help(
                     cx,
@@ -54,7 +56,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
                     expr.span,
                     &format!(
                         "allocating a local array larger than {} bytes",
-                        self.maximum_allowed_size
+                        self.maximum_allowed_size.current()
                     ),
                     None,
                     &format!(
@@ -65,4 +67,6 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, maximum_allowed_size);
 }
@@ -48,6 +48,7 @@ extern crate rustc_typeck;
 use crate::utils::parse_msrv;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_lint::LintId;
+use rustc_semver::RustcVersion;
 use rustc_session::Session;
 
 /// Macro used to declare a Clippy lint.
@@ -183,7 +184,9 @@ mod dbg_macro;
 mod default;
 mod dereference;
 mod derive;
+mod disallowed_macro;
 mod disallowed_method;
+mod disallowed_type;
 mod doc;
 mod double_comparison;
 mod double_parens;
@@ -219,6 +222,7 @@ mod if_let_some_result;
 mod if_not_else;
 mod implicit_return;
 mod implicit_saturating_sub;
+mod incompatible_msrv;
 mod indexing_slicing;
 mod infinite_iter;
 mod inherent_impl;
@@ -407,12 +411,7 @@ pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
 
             // all conf errors are non-fatal, we just use the default conf in case of error
             for error in errors {
-                sess.struct_err(&format!(
-                    "error reading Clippy's configuration file `{}`: {}",
-                    file_name.display(),
-                    error
-                ))
-                .emit();
+                emit_conf_error(sess, &file_name, error);
             }
 
             conf
@@ -426,6 +425,143 @@ pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
     }
 }
 
+/// Report an error found while reading the configuration file `file_name`, pointing at the
+/// position in the file it was found at when it is known.
+fn emit_conf_error(sess: &Session, file_name: &std::path::Path, error: utils::conf::Error) {
+    use rustc_span::{BytePos, Pos, Span};
+    use utils::conf::Error;
+
+    if let Error::Inherited(file_name, error) = error {
+        return emit_conf_error(sess, &file_name, *error);
+    }
+
+    let span = match &error {
+        Error::Spanned { span, .. } | Error::Deprecated { span, .. } => {
+            sess.source_map().load_file(file_name).ok().map(|file| {
+                let pos = |offset| file.start_pos + BytePos::from_usize(offset);
+                Span::with_root_ctxt(pos(span.start), pos(span.end))
+            })
+        },
+        _ => None,
+    };
+
+    let mut diag = match (&error, span) {
+        (Error::Deprecated { .. }, Some(span)) => sess.struct_span_warn(span, &error.to_string()),
+        (Error::Deprecated { .. }, None) => sess.struct_warn(&format!(
+            "in Clippy's configuration file `{}`: {}",
+            file_name.display(),
+            error
+        )),
+        (_, Some(span)) => sess.struct_span_err(span, &format!("error reading Clippy's configuration file: {}", error)),
+        (_, None) => sess.struct_err(&format!(
+            "error reading Clippy's configuration file `{}`: {}",
+            file_name.display(),
+            error
+        )),
+    };
+    if let Error::Spanned { help: Some(help), .. } = &error {
+        diag.help(help);
+    }
+    diag.emit();
+}
+
+/// Read the MSRV from the configuration file, or failing that from the package manifest, where
+/// `[package.metadata.clippy] msrv` takes precedence over `rust-version`. A warning is emitted for
+/// each source which disagrees with the one used.
+fn read_msrv(conf: &Conf, sess: &Session) -> Option<RustcVersion> {
+    let mut sources: Vec<(String, &str)> = Vec::new();
+    if let Some(msrv) = &conf.msrv {
+        sources.push(("Clippy's configuration file".to_string(), msrv));
+    }
+    let package_msrvs = utils::conf::package_msrvs();
+    for (key, msrv) in &package_msrvs {
+        sources.push((format!("the package's `{}`", key), msrv));
+    }
+
+    let mut versions = sources.iter().filter_map(|(source, msrv)| {
+        let version = parse_msrv(msrv, None, None);
+        if version.is_none() {
+            sess.err(&format!(
+                "error reading {}. `{}` is not a valid Rust version",
+                source, msrv
+            ));
+        }
+        Some((source, *msrv, version?))
+    });
+    let (source, msrv, version) = versions.next()?;
+    for (other_source, other_msrv, other_version) in versions {
+        if other_version != version {
+            sess.warn(&format!(
+                "the MSRV `{}` in {} differs from the MSRV `{}` in {}, which is used",
+                other_msrv, other_source, msrv, source
+            ));
+        }
+    }
+    Some(version)
+}
+
+/// Read the lint levels set in the configuration file, for the given profile.
+///
+/// This runs before the session is created, so errors in the configuration file are ignored here
+/// and reported later by [`read_conf`].
+///
+/// Used in `./src/driver.rs`.
+pub fn read_conf_lint_levels(profile: Option<&str>) -> Vec<(String, rustc_lint::Level)> {
+    let conf = match utils::conf::lookup_conf_file() {
+        Ok(Some(path)) => utils::conf::read(&path).0,
+        _ => return Vec::new(),
+    };
+    // the lints and groups of rustc are set without the `clippy::` prefix
+    let rustc_lints = rustc_lint::new_lint_store(false, false);
+    let mut rustc_names: FxHashSet<String> = rustc_lints.get_lints().iter().map(|lint| lint.name_lower()).collect();
+    rustc_names.extend(
+        rustc_lints
+            .get_lint_groups()
+            .into_iter()
+            .map(|(name, ..)| name.to_string()),
+    );
+    rustc_names.insert("warnings".to_string());
+    conf.lint_levels(profile, |name| rustc_names.contains(name))
+}
+
+/// Only report the lints on the lines changed since the git revision `rev`, or in the items
+/// containing them if `items` is set. This has to be called before [`register_plugins`].
+///
+/// Used in `./src/driver.rs`.
+pub fn register_changed_lines(store: &mut rustc_lint::LintStore, sess: &Session, rev: &str, items: bool) {
+    utils::changed_lines::read(sess, rev);
+    if items {
+        store.register_early_pass(|| box utils::changed_lines::ChangedItems);
+    }
+}
+
+/// Measure the time spent in each lint pass registered by `register`, and record it when the
+/// lint checking of the crate is done, for [`lint_timings`].
+///
+/// Used in `./src/driver.rs`.
+pub fn register_timed(store: &mut rustc_lint::LintStore, register: impl FnOnce(&mut rustc_lint::LintStore)) {
+    let (early, late) = (store.early_passes.len(), store.late_passes.len());
+    register(store);
+    utils::timings::time_passes(store, early, late);
+}
+
+/// The time spent in each lint pass registered with [`register_timed`] while checking the crate
+/// `krate`, as a table sorted by time or as JSON.
+///
+/// Used in `./src/driver.rs`.
+pub fn lint_timings(krate: &str, json: bool) -> String {
+    utils::timings::report(krate, json)
+}
+
+/// Skip the late lint passes none of whose lints can fire in the expanded crate `krate`. This has
+/// to be called before the late lint checks. The early lint passes are skipped by the lints read
+/// before the expansion.
+///
+/// Used in `./src/driver.rs`.
+pub fn read_enabled_lints(sess: &Session, store: &rustc_lint::LintStore, krate: &rustc_ast::ast::Crate) {
+    utils::enabled_lints::read(sess, store, krate);
+}
+
 /// Register all lints and lint groups with the rustc plugin registry
 ///
 /// Used in `./src/driver.rs`.
@@ -433,6 +569,12 @@ pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
 #[rustfmt::skip]
 pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
     register_removed_non_tool_lints(store);
+    utils::excluded_files::read(conf);
+    utils::allow_in_tests::read(conf);
+    store.register_pre_expansion_pass(|| box utils::allow_in_tests::TestCodeCollector);
+    store.register_pre_expansion_pass(|| box utils::enabled_lints::EnabledLintsCollector);
+    store.register_early_pass(|| box utils::allow_in_tests::TestCodeCollector);
+    store.register_early_pass(|| box utils::excluded_files::ExclusionCollector);
 
     // begin deprecated lints, do not remove this comment, it’s used in `update_lints`
     store.register_removed(
@@ -540,6 +682,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::PRODUCE_ICE,
         #[cfg(feature = "internal-lints")]
+        &utils::internal_lints::UNCHECKED_MSRV_SUGGESTION,
+        #[cfg(feature = "internal-lints")]
         &utils::internal_lints::UNNECESSARY_SYMBOL_STR,
         &approx_const::APPROX_CONSTANT,
         &arithmetic::FLOAT_ARITHMETIC,
@@ -559,6 +703,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &attrs::INLINE_ALWAYS,
         &attrs::MISMATCHED_TARGET_OS,
         &attrs::USELESS_ATTRIBUTE,
+        &await_holding_invalid::AWAIT_HOLDING_INVALID_TYPE,
         &await_holding_invalid::AWAIT_HOLDING_LOCK,
         &await_holding_invalid::AWAIT_HOLDING_REFCELL_REF,
         &bit_mask::BAD_BIT_MASK,
@@ -590,7 +735,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &derive::DERIVE_ORD_XOR_PARTIAL_ORD,
         &derive::EXPL_IMPL_CLONE_ON_COPY,
         &derive::UNSAFE_DERIVE_DESERIALIZE,
+        &disallowed_macro::DISALLOWED_MACRO,
         &disallowed_method::DISALLOWED_METHOD,
+        &disallowed_type::DISALLOWED_TYPE,
         &doc::DOC_MARKDOWN,
         &doc::MISSING_ERRORS_DOC,
         &doc::MISSING_PANICS_DOC,
@@ -652,6 +799,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &if_not_else::IF_NOT_ELSE,
         &implicit_return::IMPLICIT_RETURN,
         &implicit_saturating_sub::IMPLICIT_SATURATING_SUB,
+        &incompatible_msrv::INCOMPATIBLE_MSRV,
         &indexing_slicing::INDEXING_SLICING,
         &indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         &infinite_iter::INFINITE_ITER,
@@ -985,6 +1133,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &zero_sized_map_values::ZERO_SIZED_MAP_VALUES,
     ]);
     // end register lints, do not remove this comment, it’s used in `update_lints`
+    utils::allow_in_tests::check_lint_names(sess, store);
+
+    // the passes none of whose lints can fire are skipped, see `utils::enabled_lints`
+    let (early_passes, late_passes) = (store.early_passes.len(), store.late_passes.len());
 
     // all the internal lints
     #[cfg(feature = "internal-lints")]
@@ -999,9 +1151,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         store.register_late_pass(|| box utils::internal_lints::LintWithoutLintPass::default());
         store.register_late_pass(|| box utils::internal_lints::MatchTypeOnDiagItem);
         store.register_late_pass(|| box utils::internal_lints::OuterExpnDataPass);
+        store.register_late_pass(|| box utils::internal_lints::UncheckedMsrvSuggestion::default());
     }
     store.register_late_pass(|| box utils::author::Author);
-    store.register_late_pass(|| box await_holding_invalid::AwaitHolding);
+    let await_holding_invalid_types = conf.await_holding_invalid_types.clone();
+    store.register_late_pass(move || box await_holding_invalid::AwaitHolding::new(&await_holding_invalid_types));
     store.register_late_pass(|| box serde_api::SerdeApi);
     let vec_box_size_threshold = conf.vec_box_size_threshold;
     store.register_late_pass(move || box types::Types::new(vec_box_size_threshold));
@@ -1032,12 +1186,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box implicit_return::ImplicitReturn);
     store.register_late_pass(|| box implicit_saturating_sub::ImplicitSaturatingSub);
 
-    let msrv = conf.msrv.as_ref().and_then(|s| {
-        parse_msrv(s, None, None).or_else(|| {
-            sess.err(&format!("error reading Clippy's configuration file. `{}` is not a valid Rust version", s));
-            None
-        })
-    });
+    let msrv = read_msrv(conf, sess);
 
     store.register_late_pass(move || box methods::Methods::new(msrv));
     store.register_late_pass(move || box matches::Matches::new(msrv));
@@ -1052,14 +1201,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box use_self::UseSelf::new(msrv));
     store.register_late_pass(move || box missing_const_for_fn::MissingConstForFn::new(msrv));
     store.register_late_pass(move || box needless_question_mark::NeedlessQuestionMark::new(msrv));
+    store.register_late_pass(move || box incompatible_msrv::IncompatibleMsrv::new(msrv));
 
     store.register_late_pass(|| box size_of_in_element_count::SizeOfInElementCount);
-    store.register_late_pass(|| box map_clone::MapClone);
+    store.register_late_pass(move || box map_clone::MapClone::new(msrv));
     store.register_late_pass(|| box map_err_ignore::MapErrIgnore);
     store.register_late_pass(|| box shadow::Shadow);
     store.register_late_pass(|| box types::LetUnitValue);
     store.register_late_pass(|| box types::UnitCmp);
-    store.register_late_pass(|| box loops::Loops);
+    store.register_late_pass(move || box loops::Loops::new(msrv));
     store.register_late_pass(|| box main_recursion::MainRecursion::default());
     store.register_late_pass(|| box lifetimes::Lifetimes);
     store.register_late_pass(|| box entry::HashMapPass);
@@ -1075,12 +1225,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box needless_borrowed_ref::NeedlessBorrowedRef);
     store.register_late_pass(|| box no_effect::NoEffect);
     store.register_late_pass(|| box temporary_assignment::TemporaryAssignment);
-    store.register_late_pass(|| box transmute::Transmute);
+    store.register_late_pass(move || box transmute::Transmute::new(msrv));
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
     store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
     let too_large_for_stack = conf.too_large_for_stack;
-    store.register_late_pass(move || box escape::BoxedLocal{too_large_for_stack});
-    store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
+    store.register_late_pass(move || box escape::BoxedLocal::new(too_large_for_stack));
+    store.register_late_pass(move || box vec::UselessVec::new(too_large_for_stack));
     store.register_late_pass(|| box panic_unimplemented::PanicUnimplemented);
     store.register_late_pass(|| box strings::StringLitAsBytes);
     store.register_late_pass(|| box derive::Derive);
@@ -1126,7 +1276,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         conf.pass_by_value_size_limit,
         &sess.target,
     );
-    store.register_late_pass(move || box pass_by_ref_or_value);
+    store.register_late_pass(move || box pass_by_ref_or_value.clone());
     store.register_late_pass(|| box ref_option_ref::RefOptionRef);
     store.register_late_pass(|| box try_err::TryErr);
     store.register_late_pass(|| box bytecount::ByteCount);
@@ -1228,9 +1378,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box vec_resize_to_zero::VecResizeToZero);
     store.register_late_pass(|| box panic_in_result_fn::PanicInResultFn);
     let single_char_binding_names_threshold = conf.single_char_binding_names_threshold;
-    store.register_early_pass(move || box non_expressive_names::NonExpressiveNames {
-        single_char_binding_names_threshold,
-    });
+    store.register_early_pass(move || box non_expressive_names::NonExpressiveNames::new(single_char_binding_names_threshold));
     store.register_early_pass(|| box unnested_or_patterns::UnnestedOrPatterns);
     store.register_late_pass(|| box macro_use::MacroUseImports::default());
     store.register_late_pass(|| box map_identity::MapIdentity);
@@ -1244,8 +1392,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box float_equality_without_abs::FloatEqualityWithoutAbs);
     store.register_late_pass(|| box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
     store.register_late_pass(|| box async_yields_async::AsyncYieldsAsync);
-    let disallowed_methods = conf.disallowed_methods.iter().cloned().collect::<FxHashSet<_>>();
+    let disallowed_methods = conf.disallowed_methods.clone();
     store.register_late_pass(move || box disallowed_method::DisallowedMethod::new(&disallowed_methods));
+    let disallowed_types = conf.disallowed_types.clone();
+    store.register_late_pass(move || box disallowed_type::DisallowedType::new(&disallowed_types));
+    let disallowed_macros = conf.disallowed_macros.clone();
+    store.register_late_pass(move || box disallowed_macro::DisallowedMacro::new(&disallowed_macros));
     store.register_early_pass(|| box asm_syntax::InlineAsmX86AttSyntax);
     store.register_early_pass(|| box asm_syntax::InlineAsmX86IntelSyntax);
     store.register_late_pass(|| box undropped_manually_drops::UndroppedManuallyDrops);
@@ -1256,6 +1408,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box types::PtrAsPtr::new(msrv));
     store.register_late_pass(|| box case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons);
     store.register_late_pass(|| box redundant_slicing::RedundantSlicing);
+    utils::enabled_lints::skip_disabled_passes(store, early_passes, late_passes, PASS_LINTS);
 
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
         LintId::of(&arithmetic::FLOAT_ARITHMETIC),
@@ -1408,6 +1561,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&utils::internal_lints::MATCH_TYPE_ON_DIAGNOSTIC_ITEM),
         LintId::of(&utils::internal_lints::OUTER_EXPN_EXPN_DATA),
         LintId::of(&utils::internal_lints::PRODUCE_ICE),
+        LintId::of(&utils::internal_lints::UNCHECKED_MSRV_SUGGESTION),
         LintId::of(&utils::internal_lints::UNNECESSARY_SYMBOL_STR),
     ]);
 
@@ -1423,6 +1577,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&attrs::DEPRECATED_SEMVER),
         LintId::of(&attrs::MISMATCHED_TARGET_OS),
         LintId::of(&attrs::USELESS_ATTRIBUTE),
+        LintId::of(&await_holding_invalid::AWAIT_HOLDING_INVALID_TYPE),
         LintId::of(&bit_mask::BAD_BIT_MASK),
         LintId::of(&bit_mask::INEFFECTIVE_BIT_MASK),
         LintId::of(&blacklisted_name::BLACKLISTED_NAME),
@@ -1439,6 +1594,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
         LintId::of(&derive::DERIVE_ORD_XOR_PARTIAL_ORD),
+        LintId::of(&disallowed_macro::DISALLOWED_MACRO),
+        LintId::of(&disallowed_method::DISALLOWED_METHOD),
+        LintId::of(&disallowed_type::DISALLOWED_TYPE),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
@@ -1706,6 +1864,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
         LintId::of(&assign_ops::ASSIGN_OP_PATTERN),
         LintId::of(&attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
+        LintId::of(&await_holding_invalid::AWAIT_HOLDING_INVALID_TYPE),
         LintId::of(&blacklisted_name::BLACKLISTED_NAME),
         LintId::of(&blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS),
         LintId::of(&collapsible_if::COLLAPSIBLE_ELSE_IF),
@@ -1713,6 +1872,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&collapsible_match::COLLAPSIBLE_MATCH),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
+        LintId::of(&disallowed_macro::DISALLOWED_MACRO),
+        LintId::of(&disallowed_method::DISALLOWED_METHOD),
+        LintId::of(&disallowed_type::DISALLOWED_TYPE),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&enum_variants::ENUM_VARIANT_NAMES),
@@ -2011,11 +2173,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
         LintId::of(&attrs::EMPTY_LINE_AFTER_OUTER_ATTR),
         LintId::of(&cognitive_complexity::COGNITIVE_COMPLEXITY),
-        LintId::of(&disallowed_method::DISALLOWED_METHOD),
         LintId::of(&fallible_impl_from::FALLIBLE_IMPL_FROM),
         LintId::of(&floating_point_arithmetic::IMPRECISE_FLOPS),
         LintId::of(&floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(&future_not_send::FUTURE_NOT_SEND),
+        LintId::of(&incompatible_msrv::INCOMPATIBLE_MSRV),
         LintId::of(&let_if_seq::USELESS_LET_IF_SEQ),
         LintId::of(&missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(&mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
@@ -2030,6 +2192,306 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     ]);
 }
 
+/// The lints of each lint pass registered by [`register_plugins`], by the name of the pass. The
+/// passes which are not listed always run.
+#[rustfmt::skip]
+const PASS_LINTS: utils::enabled_lints::PassLints = &[
+    // begin pass lints, do not remove this comment, it’s used in `update_lints`
+    ("AbsurdExtremeComparisons", &["absurd_extreme_comparisons"]),
+    ("ApproxConstant", &["approx_constant"]),
+    ("Arithmetic", &["integer_arithmetic", "float_arithmetic"]),
+    ("AsConversions", &["as_conversions"]),
+    ("AssertionsOnConstants", &["assertions_on_constants"]),
+    ("AssignOps", &["assign_op_pattern", "misrefactored_assign_op"]),
+    ("AsyncYieldsAsync", &["async_yields_async"]),
+    ("AtomicOrdering", &["invalid_atomic_ordering"]),
+    ("Attributes", &["inline_always", "deprecated_semver", "useless_attribute", "blanket_clippy_restriction_lints"]),
+    ("AwaitHolding", &["await_holding_lock", "await_holding_refcell_ref", "await_holding_invalid_type"]),
+    ("BitMask", &["bad_bit_mask", "ineffective_bit_mask", "verbose_bit_mask"]),
+    ("BlacklistedName", &["blacklisted_name"]),
+    ("BlocksInIfConditions", &["blocks_in_if_conditions"]),
+    ("BoolComparison", &["bool_comparison"]),
+    ("BoxedLocal", &["boxed_local"]),
+    ("ByteCount", &["naive_bytecount"]),
+    ("CargoCommonMetadata", &["cargo_common_metadata"]),
+    ("CaseSensitiveFileExtensionComparisons", &["case_sensitive_file_extension_comparisons"]),
+    ("Casts", &[
+        "cast_precision_loss", "cast_sign_loss", "cast_possible_truncation", "cast_possible_wrap", "cast_lossless",
+        "unnecessary_cast", "cast_ptr_alignment", "fn_to_numeric_cast", "fn_to_numeric_cast_with_truncation",
+    ]),
+    ("CharLitAsU8", &["char_lit_as_u8"]),
+    ("CheckedConversions", &["checked_conversions"]),
+    ("ClippyLintsInternal", &["clippy_lints_internal"]),
+    ("CognitiveComplexity", &["cognitive_complexity"]),
+    ("CollapsibleCalls", &["collapsible_span_lint_calls"]),
+    ("CollapsibleIf", &["collapsible_if", "collapsible_else_if"]),
+    ("CollapsibleMatch", &["collapsible_match"]),
+    ("ComparisonChain", &["comparison_chain"]),
+    ("CompilerLintFunctions", &["compiler_lint_functions"]),
+    ("CopyAndPaste", &["ifs_same_cond", "same_functions_in_if_condition", "if_same_then_else"]),
+    ("CopyIterator", &["copy_iterator"]),
+    ("CreateDir", &["create_dir"]),
+    ("DbgMacro", &["dbg_macro"]),
+    ("DebugAssertWithMutCall", &["debug_assert_with_mut_call"]),
+    ("DecimalLiteralRepresentation", &["decimal_literal_representation"]),
+    ("Default", &["default_trait_access", "field_reassign_with_default"]),
+    ("DerefAddrOf", &["deref_addrof"]),
+    ("Dereferencing", &["explicit_deref_methods"]),
+    ("Derive", &[
+        "expl_impl_clone_on_copy", "derive_hash_xor_eq", "derive_ord_xor_partial_ord", "unsafe_derive_deserialize",
+    ]),
+    ("DisallowedMacro", &["disallowed_macro"]),
+    ("DisallowedMethod", &["disallowed_method"]),
+    ("DisallowedType", &["disallowed_type"]),
+    ("DocMarkdown", &[
+        "doc_markdown", "missing_safety_doc", "missing_errors_doc", "missing_panics_doc", "needless_doctest_main",
+    ]),
+    ("DoubleComparisons", &["double_comparisons"]),
+    ("DoubleParens", &["double_parens"]),
+    ("DropForgetRef", &["drop_ref", "forget_ref", "drop_copy", "forget_copy"]),
+    ("DurationSubsec", &["duration_subsec"]),
+    ("EarlyAttributes", &["deprecated_cfg_attr", "mismatched_target_os", "empty_line_after_outer_attr"]),
+    ("ElseIfWithoutElse", &["else_if_without_else"]),
+    ("EmptyEnum", &["empty_enum"]),
+    ("EnumVariantNames", &[
+        "enum_variant_names", "pub_enum_variant_names", "module_name_repetitions", "module_inception",
+    ]),
+    ("EqOp", &["eq_op", "op_ref"]),
+    ("ErasingOp", &["erasing_op"]),
+    ("EtaReduction", &["redundant_closure", "redundant_closure_for_method_calls"]),
+    ("EvalOrderDependence", &["eval_order_dependence", "diverging_sub_expression"]),
+    ("ExcessiveBools", &["struct_excessive_bools", "fn_params_excessive_bools"]),
+    ("ExhaustiveItems", &["exhaustive_enums", "exhaustive_structs"]),
+    ("Exit", &["exit"]),
+    ("ExplicitWrite", &["explicit_write"]),
+    ("FallibleImplFrom", &["fallible_impl_from"]),
+    ("FloatEqualityWithoutAbs", &["float_equality_without_abs"]),
+    ("FloatLiteral", &["excessive_precision", "lossy_float_literal"]),
+    ("FloatingPointArithmetic", &["imprecise_flops", "suboptimal_flops"]),
+    ("Formatting", &[
+        "suspicious_assignment_formatting", "suspicious_unary_op_formatting", "suspicious_else_formatting",
+        "possible_missing_comma",
+    ]),
+    ("FromOverInto", &["from_over_into"]),
+    ("Functions", &[
+        "too_many_arguments", "too_many_lines", "not_unsafe_ptr_arg_deref", "must_use_unit", "double_must_use",
+        "must_use_candidate", "result_unit_err",
+    ]),
+    ("FutureNotSend", &["future_not_send"]),
+    ("GetLastWithLen", &["get_last_with_len"]),
+    ("HashMapPass", &["map_entry"]),
+    ("IdentityOp", &["identity_op"]),
+    ("IfLetMutex", &["if_let_mutex"]),
+    ("IfNotElse", &["if_not_else"]),
+    ("ImplicitHasher", &["implicit_hasher"]),
+    ("ImplicitReturn", &["implicit_return"]),
+    ("ImplicitSaturatingSub", &["implicit_saturating_sub"]),
+    ("IncompatibleMsrv", &["incompatible_msrv"]),
+    ("IndexingSlicing", &["indexing_slicing", "out_of_bounds_indexing"]),
+    ("InfiniteIter", &["infinite_iter", "maybe_infinite_iter"]),
+    ("InherentToString", &["inherent_to_string", "inherent_to_string_shadow_display"]),
+    ("InlineAsmX86AttSyntax", &["inline_asm_x86_att_syntax"]),
+    ("InlineAsmX86IntelSyntax", &["inline_asm_x86_intel_syntax"]),
+    ("InlineFnWithoutBody", &["inline_fn_without_body"]),
+    ("IntPlusOne", &["int_plus_one"]),
+    ("IntegerDivision", &["integer_division"]),
+    ("InterningDefinedSymbol", &["interning_defined_symbol", "unnecessary_symbol_str"]),
+    ("InvalidPaths", &["invalid_paths"]),
+    ("InvalidUpcastComparisons", &["invalid_upcast_comparisons"]),
+    ("ItemsAfterStatements", &["items_after_statements"]),
+    ("LargeConstArrays", &["large_const_arrays"]),
+    ("LargeEnumVariant", &["large_enum_variant"]),
+    ("LargeStackArrays", &["large_stack_arrays"]),
+    ("LenZero", &["len_zero", "len_without_is_empty", "comparison_to_empty"]),
+    ("LetIfSeq", &["useless_let_if_seq"]),
+    ("LetUnderscore", &["let_underscore_must_use", "let_underscore_lock", "let_underscore_drop"]),
+    ("LetUnitValue", &["let_unit_value"]),
+    ("Lifetimes", &["needless_lifetimes", "extra_unused_lifetimes"]),
+    ("LintWithoutLintPass", &["default_lint", "lint_without_lint_pass"]),
+    ("LiteralDigitGrouping", &[
+        "unreadable_literal", "inconsistent_digit_grouping", "large_digit_groups", "mistyped_literal_suffixes",
+        "unusual_byte_groupings",
+    ]),
+    ("Loops", &[
+        "manual_memcpy", "manual_flatten", "needless_range_loop", "explicit_iter_loop", "explicit_into_iter_loop",
+        "iter_next_loop", "for_loops_over_fallibles", "while_let_loop", "needless_collect", "explicit_counter_loop",
+        "empty_loop", "while_let_on_iterator", "for_kv_map", "never_loop", "mut_range_bound",
+        "while_immutable_condition", "same_item_push", "single_element_loop",
+    ]),
+    ("MacroUseImports", &["macro_use_imports"]),
+    ("MainRecursion", &["main_recursion"]),
+    ("ManualAsyncFn", &["manual_async_fn"]),
+    ("ManualNonExhaustive", &["manual_non_exhaustive"]),
+    ("ManualOkOr", &["manual_ok_or"]),
+    ("ManualStrip", &["manual_strip"]),
+    ("ManualUnwrapOr", &["manual_unwrap_or"]),
+    ("MapClone", &["map_clone"]),
+    ("MapErrIgnore", &["map_err_ignore"]),
+    ("MapIdentity", &["map_identity"]),
+    ("MapUnit", &["option_map_unit_fn", "result_map_unit_fn"]),
+    ("MatchOnVecItems", &["match_on_vec_items"]),
+    ("MatchTypeOnDiagItem", &["match_type_on_diagnostic_item"]),
+    ("Matches", &[
+        "single_match", "match_ref_pats", "match_bool", "single_match_else", "match_overlapping_arm",
+        "match_wild_err_arm", "match_as_ref", "wildcard_enum_match_arm", "match_wildcard_for_single_variants",
+        "wildcard_in_or_patterns", "match_single_binding", "infallible_destructuring_match",
+        "rest_pat_in_fully_bound_structs", "redundant_pattern_matching", "match_like_matches_macro", "match_same_arms",
+    ]),
+    ("MemDiscriminant", &["mem_discriminant_non_enum"]),
+    ("MemForget", &["mem_forget"]),
+    ("MemReplace", &["mem_replace_option_with_none", "mem_replace_with_uninit", "mem_replace_with_default"]),
+    ("Methods", &[
+        "unwrap_used", "expect_used", "should_implement_trait", "wrong_self_convention", "wrong_pub_self_convention",
+        "ok_expect", "map_unwrap_or", "result_map_or_into_option", "option_map_or_none", "bind_instead_of_map",
+        "or_fun_call", "expect_fun_call", "chars_next_cmp", "chars_last_cmp", "clone_on_copy", "clone_on_ref_ptr",
+        "clone_double_ref", "inefficient_to_string", "new_ret_no_self", "single_char_pattern", "single_char_add_str",
+        "search_is_some", "filter_next", "skip_while_next", "filter_map", "filter_map_identity", "manual_filter_map",
+        "manual_find_map", "filter_map_next", "flat_map_identity", "map_flatten", "iterator_step_by_zero",
+        "iter_next_slice", "iter_nth", "iter_nth_zero", "bytes_nth", "iter_skip_next", "get_unwrap",
+        "string_extend_chars", "iter_cloned_collect", "useless_asref", "unnecessary_fold", "unnecessary_filter_map",
+        "into_iter_on_ref", "suspicious_map", "uninit_assumed_init", "manual_saturating_arithmetic", "zst_offset",
+        "filetype_is_file", "option_as_ref_deref", "unnecessary_lazy_evaluations", "map_collect_result_unit",
+        "from_iter_instead_of_collect", "inspect_for_each",
+    ]),
+    ("MinMaxPass", &["min_max"]),
+    ("MiscEarlyLints", &[
+        "unneeded_field_pattern", "duplicate_underscore_argument", "double_neg", "mixed_case_hex_literals",
+        "unseparated_literal_suffix", "zero_prefixed_literal", "builtin_type_shadow", "redundant_pattern",
+        "unneeded_wildcard_pattern",
+    ]),
+    ("MiscLints", &[
+        "toplevel_ref_arg", "cmp_nan", "float_cmp", "cmp_owned", "modulo_one", "used_underscore_binding",
+        "short_circuit_statement", "zero_ptr", "float_cmp_const",
+    ]),
+    ("MissingConstForFn", &["missing_const_for_fn"]),
+    ("MissingDoc", &["missing_docs_in_private_items"]),
+    ("MissingInline", &["missing_inline_in_public_items"]),
+    ("ModuloArithmetic", &["modulo_arithmetic"]),
+    ("MultipleCrateVersions", &["multiple_crate_versions"]),
+    ("MultipleInherentImpl", &["multiple_inherent_impl"]),
+    ("MutMut", &["mut_mut"]),
+    ("MutMutexLock", &["mut_mutex_lock"]),
+    ("MutableKeyType", &["mutable_key_type"]),
+    ("Mutex", &["mutex_atomic", "mutex_integer"]),
+    ("NeedlessArbitrarySelfType", &["needless_arbitrary_self_type"]),
+    ("NeedlessBool", &["needless_bool"]),
+    ("NeedlessBorrow", &["needless_borrow"]),
+    ("NeedlessBorrowedRef", &["needless_borrowed_reference"]),
+    ("NeedlessContinue", &["needless_continue"]),
+    ("NeedlessPassByValue", &["needless_pass_by_value"]),
+    ("NeedlessQuestionMark", &["needless_question_mark"]),
+    ("NeedlessUpdate", &["needless_update"]),
+    ("NegMultiply", &["neg_multiply"]),
+    ("NewWithoutDefault", &["new_without_default"]),
+    ("NoEffect", &["no_effect", "unnecessary_operation"]),
+    ("NoNegCompOpForPartialOrd", &["neg_cmp_op_on_partial_ord"]),
+    ("NonCopyConst", &["declare_interior_mutable_const", "borrow_interior_mutable_const"]),
+    ("NonExpressiveNames", &["similar_names", "many_single_char_names", "just_underscores_and_digits"]),
+    ("NonminimalBool", &["nonminimal_bool", "logic_bug"]),
+    ("OkIfLet", &["if_let_some_result"]),
+    ("OpenOptions", &["nonsensical_open_options"]),
+    ("OptionEnvUnwrap", &["option_env_unwrap"]),
+    ("OptionIfLetElse", &["option_if_let_else"]),
+    ("OuterExpnDataPass", &["outer_expn_expn_data"]),
+    ("OverflowCheckConditional", &["overflow_check_conditional"]),
+    ("PanicInResultFn", &["panic_in_result_fn"]),
+    ("PanicUnimplemented", &["unimplemented", "unreachable", "todo", "panic"]),
+    ("PartialEqNeImpl", &["partialeq_ne_impl"]),
+    ("PassByRefOrValue", &["trivially_copy_pass_by_ref", "large_types_passed_by_value"]),
+    ("PathBufPushOverwrite", &["path_buf_push_overwrite"]),
+    ("PatternTypeMismatch", &["pattern_type_mismatch"]),
+    ("Precedence", &["precedence"]),
+    ("ProduceIce", &["produce_ice"]),
+    ("Ptr", &["ptr_arg", "cmp_null", "mut_from_ref"]),
+    ("PtrAsPtr", &["ptr_as_ptr"]),
+    ("PtrEq", &["ptr_eq"]),
+    ("PtrOffsetWithCast", &["ptr_offset_with_cast"]),
+    ("QuestionMark", &["question_mark"]),
+    ("Ranges", &[
+        "range_zip_with_len", "range_plus_one", "range_minus_one", "reversed_empty_ranges", "manual_range_contains",
+    ]),
+    ("RedundantClone", &["redundant_clone"]),
+    ("RedundantClosureCall", &["redundant_closure_call"]),
+    ("RedundantElse", &["redundant_else"]),
+    ("RedundantFieldNames", &["redundant_field_names"]),
+    ("RedundantPubCrate", &["redundant_pub_crate"]),
+    ("RedundantSlicing", &["redundant_slicing"]),
+    ("RedundantStaticLifetimes", &["redundant_static_lifetimes"]),
+    ("RefInDeref", &["ref_in_deref"]),
+    ("RefOptionRef", &["ref_option_ref"]),
+    ("RefToMut", &["cast_ref_to_mut"]),
+    ("Regex", &["invalid_regex", "trivial_regex"]),
+    ("RepeatOnce", &["repeat_once"]),
+    ("Return", &["let_and_return", "needless_return"]),
+    ("SelfAssignment", &["self_assignment"]),
+    ("SemicolonIfNothingReturned", &["semicolon_if_nothing_returned"]),
+    ("SerdeApi", &["serde_api_misuse"]),
+    ("Shadow", &["shadow_same", "shadow_reuse", "shadow_unrelated"]),
+    ("SingleComponentPathImports", &["single_component_path_imports"]),
+    ("SizeOfInElementCount", &["size_of_in_element_count"]),
+    ("SlowVectorInit", &["slow_vector_initialization"]),
+    ("StableSortPrimitive", &["stable_sort_primitive"]),
+    ("StrToString", &["str_to_string"]),
+    ("StringAdd", &["string_add", "string_add_assign"]),
+    ("StringLitAsBytes", &["string_lit_as_bytes", "string_from_utf8_as_bytes"]),
+    ("StringToString", &["string_to_string"]),
+    ("SuspiciousImpl", &["suspicious_arithmetic_impl", "suspicious_op_assign_impl"]),
+    ("SuspiciousOperationGroupings", &["suspicious_operation_groupings"]),
+    ("Swap", &["manual_swap", "almost_swapped"]),
+    ("TabsInDocComments", &["tabs_in_doc_comments"]),
+    ("TemporaryAssignment", &["temporary_assignment"]),
+    ("ToDigitIsSome", &["to_digit_is_some"]),
+    ("ToStringInDisplay", &["to_string_in_display"]),
+    ("TraitBounds", &["type_repetition_in_bounds", "trait_duplication_in_bounds"]),
+    ("Transmute", &[
+        "crosspointer_transmute", "transmute_ptr_to_ref", "transmute_ptr_to_ptr", "useless_transmute",
+        "wrong_transmute", "transmute_int_to_char", "transmute_bytes_to_str", "transmute_int_to_bool",
+        "transmute_int_to_float", "transmute_float_to_int", "unsound_collection_transmute",
+        "transmutes_expressible_as_ptr_casts",
+    ]),
+    ("TransmutingNull", &["transmuting_null"]),
+    ("TryErr", &["try_err"]),
+    ("TypeComplexity", &["type_complexity"]),
+    ("Types", &[
+        "box_vec", "vec_box", "option_option", "linkedlist", "borrowed_box", "redundant_allocation", "rc_buffer",
+    ]),
+    ("UncheckedMsrvSuggestion", &["unchecked_msrv_suggestion"]),
+    ("UndroppedManuallyDrops", &["undropped_manually_drops"]),
+    ("Unicode", &["invisible_characters", "non_ascii_literal", "unicode_not_nfc"]),
+    ("UnitArg", &["unit_arg"]),
+    ("UnitCmp", &["unit_cmp"]),
+    ("UnitReturnExpectingOrd", &["unit_return_expecting_ord"]),
+    ("UnnamedAddress", &["fn_address_comparisons", "vtable_address_comparisons"]),
+    ("UnnecessaryMutPassed", &["unnecessary_mut_passed"]),
+    ("UnnecessarySortBy", &["unnecessary_sort_by"]),
+    ("UnnecessaryWraps", &["unnecessary_wraps"]),
+    ("UnnestedOrPatterns", &["unnested_or_patterns"]),
+    ("UnportableVariant", &["enum_clike_unportable_variant"]),
+    ("UnsafeNameRemoval", &["unsafe_removed_from_name"]),
+    ("UnusedIoAmount", &["unused_io_amount"]),
+    ("UnusedSelf", &["unused_self"]),
+    ("UnusedUnit", &["unused_unit"]),
+    ("Unwrap", &["panicking_unwrap", "unnecessary_unwrap"]),
+    ("UnwrapInResult", &["unwrap_in_result"]),
+    ("UpperCaseAcronyms", &["upper_case_acronyms"]),
+    ("UseSelf", &["use_self"]),
+    ("UselessConversion", &["useless_conversion"]),
+    ("UselessFormat", &["useless_format"]),
+    ("UselessVec", &["useless_vec"]),
+    ("VecInitThenPush", &["vec_init_then_push"]),
+    ("VecResizeToZero", &["vec_resize_to_zero"]),
+    ("VerboseFileReads", &["verbose_file_reads"]),
+    ("WildcardDependencies", &["wildcard_dependencies"]),
+    ("WildcardImports", &["enum_glob_use", "wildcard_imports"]),
+    ("Write", &[
+        "print_with_newline", "println_empty_string", "print_stdout", "print_stderr", "use_debug", "print_literal",
+        "write_with_newline", "writeln_empty_string", "write_literal",
+    ]),
+    ("ZeroDiv", &["zero_divided_by_zero"]),
+    ("ZeroSizedMapValues", &["zero_sized_map_values"]),
+    // end pass lints, do not remove this comment, it’s used in `update_lints`
+];
+
 #[rustfmt::skip]
 fn register_removed_non_tool_lints(store: &mut rustc_lint::LintStore) {
     store.register_removed(
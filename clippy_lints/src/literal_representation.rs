@@ -4,7 +4,7 @@
 use crate::utils::{
     in_macro,
     numeric_literal::{NumericLiteral, Radix},
-    snippet_opt, span_lint_and_sugg,
+    snippet_opt, span_lint_and_sugg, ConfStack,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::{Expr, ExprKind, Lit, LitKind};
@@ -408,9 +408,9 @@ impl LiteralDigitGrouping {
 }
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct DecimalLiteralRepresentation {
-    threshold: u64,
+    threshold: ConfStack<u64>,
 }
 
 impl_lint_pass!(DecimalLiteralRepresentation => [DECIMAL_LITERAL_REPRESENTATION]);
@@ -425,21 +425,25 @@ impl EarlyLintPass for DecimalLiteralRepresentation {
             self.check_lit(cx, lit)
         }
     }
+
+    extract_conf_attrs!(EarlyContext, threshold);
 }
 
 impl DecimalLiteralRepresentation {
     #[must_use]
     pub fn new(threshold: u64) -> Self {
-        Self { threshold }
+        Self {
+            threshold: ConfStack::new("literal_representation_threshold", threshold),
+        }
     }
-    fn check_lit(self, cx: &EarlyContext<'_>, lit: &Lit) {
+    fn check_lit(&self, cx: &EarlyContext<'_>, lit: &Lit) {
         // Lint integral literals.
         if_chain! {
             if let LitKind::Int(val, _) = lit.kind;
             if let Some(src) = snippet_opt(cx, lit.span);
             if let Some(num_lit) = NumericLiteral::from_lit(&src, &lit);
             if num_lit.radix == Radix::Decimal;
-            if val >= u128::from(self.threshold);
+            if val >= u128::from(*self.threshold.current());
             then {
                 let hex = format!("{:#X}", val);
                 let num_lit = NumericLiteral::new(&hex, num_lit.suffix, false);
@@ -7,7 +7,8 @@ use crate::utils::{
     indent_of, is_in_panic_handler, is_integer_const, is_no_std_crate, is_ok_ctor, is_refutable, is_some_ctor,
     is_type_diagnostic_item, last_path_segment, match_trait_method, match_type, multispan_sugg, path_to_local,
     path_to_local_id, paths, single_segment_path, snippet, snippet_with_applicability, snippet_with_macro_callsite,
-    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, sugg, SpanlessEq,
+    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, std_item_meets_msrv, sugg, MsrvStack,
+    SpanlessEq,
 };
 use if_chain::if_chain;
 use rustc_ast::ast;
@@ -25,7 +26,8 @@ use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::middle::region;
 use rustc_middle::ty::{self, Ty, TyS};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_typeck::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
@@ -524,7 +526,20 @@ declare_clippy_lint! {
     "for loops over `Option`s or `Result`s with a single expression can be simplified"
 }
 
-declare_lint_pass!(Loops => [
+pub struct Loops {
+    msrv: MsrvStack,
+}
+
+impl Loops {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl_lint_pass!(Loops => [
     MANUAL_MEMCPY,
     MANUAL_FLATTEN,
     NEEDLESS_RANGE_LOOP,
@@ -555,7 +570,7 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
             if body.span.from_expansion() {
                 return;
             }
-            check_for_loop(cx, pat, arg, body, expr, span);
+            check_for_loop(cx, pat, arg, body, expr, span, self.msrv.current());
         }
 
         // we don't want to check expanded macros
@@ -684,6 +699,8 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
 
         check_needless_collect(expr, cx);
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 enum NeverLoopResult {
@@ -851,6 +868,7 @@ fn check_for_loop<'tcx>(
     body: &'tcx Expr<'_>,
     expr: &'tcx Expr<'_>,
     span: Span,
+    msrv: Option<&RustcVersion>,
 ) {
     let is_manual_memcpy_triggered = detect_manual_memcpy(cx, pat, arg, body, expr);
     if !is_manual_memcpy_triggered {
@@ -862,7 +880,7 @@ fn check_for_loop<'tcx>(
     check_for_mut_range_bound(cx, arg, body);
     check_for_single_element_loop(cx, pat, arg, body, expr);
     detect_same_item_push(cx, pat, arg, body, expr);
-    check_manual_flatten(cx, pat, arg, body, span);
+    check_manual_flatten(cx, pat, arg, body, span, msrv);
 }
 
 // this function assumes the given expression is a `for` loop.
@@ -1975,7 +1993,11 @@ fn check_manual_flatten<'tcx>(
     arg: &'tcx Expr<'_>,
     body: &'tcx Expr<'_>,
     span: Span,
+    msrv: Option<&RustcVersion>,
 ) {
+    if !std_item_meets_msrv(cx, &paths::ITERATOR_FLATTEN, msrv) {
+        return;
+    }
     if let ExprKind::Block(ref block, _) = body.kind {
         // Ensure the `if let` statement is the only expression or statement in the for-loop
         let inner_expr = if block.stmts.len() == 1 && block.expr.is_none() {
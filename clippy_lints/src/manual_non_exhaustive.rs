@@ -1,4 +1,4 @@
-use crate::utils::{meets_msrv, snippet_opt, span_lint_and_then};
+use crate::utils::{meets_msrv, snippet_opt, span_lint_and_then, MsrvStack};
 use if_chain::if_chain;
 use rustc_ast::ast::{Attribute, Item, ItemKind, StructField, Variant, VariantData, VisibilityKind};
 use rustc_attr as attr;
@@ -60,13 +60,15 @@ declare_clippy_lint! {
 
 #[derive(Clone)]
 pub struct ManualNonExhaustive {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
 }
 
 impl ManualNonExhaustive {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
     }
 }
 
@@ -74,7 +76,7 @@ impl_lint_pass!(ManualNonExhaustive => [MANUAL_NON_EXHAUSTIVE]);
 
 impl EarlyLintPass for ManualNonExhaustive {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
-        if !meets_msrv(self.msrv.as_ref(), &MANUAL_NON_EXHAUSTIVE_MSRV) {
+        if !meets_msrv(self.msrv.current(), &MANUAL_NON_EXHAUSTIVE_MSRV) {
             return;
         }
 
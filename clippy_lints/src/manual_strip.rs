@@ -1,7 +1,7 @@
 use crate::consts::{constant, Constant};
 use crate::utils::usage::mutated_variables;
 use crate::utils::{
-    eq_expr_value, higher, match_def_path, meets_msrv, multispan_sugg, paths, snippet, span_lint_and_then,
+    eq_expr_value, higher, match_def_path, multispan_sugg, paths, snippet, span_lint_and_then, MsrvStack, StdItem,
 };
 
 use if_chain::if_chain;
@@ -18,8 +18,6 @@ use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Spanned;
 use rustc_span::Span;
 
-const MANUAL_STRIP_MSRV: RustcVersion = RustcVersion::new(1, 45, 0);
-
 declare_clippy_lint! {
     /// **What it does:**
     /// Suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing using
@@ -55,13 +53,17 @@ declare_clippy_lint! {
 }
 
 pub struct ManualStrip {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
+    strip_prefix: StdItem,
 }
 
 impl ManualStrip {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+            strip_prefix: StdItem::new(&paths::STR_STRIP_PREFIX),
+        }
     }
 }
 
@@ -75,7 +77,7 @@ enum StripKind {
 
 impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &MANUAL_STRIP_MSRV) {
+        if !self.strip_prefix.meets_msrv(cx, self.msrv.current()) {
             return;
         }
 
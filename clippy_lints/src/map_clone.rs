@@ -1,22 +1,25 @@
 use crate::utils::paths;
 use crate::utils::{
-    is_copy, is_type_diagnostic_item, match_trait_method, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
+    is_copy, is_type_diagnostic_item, match_trait_method, remove_blocks, snippet_with_applicability,
+    span_lint_and_sugg, std_item_meets_msrv, MsrvStack,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty;
 use rustc_middle::ty::adjustment::Adjust;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `map(|x| x.clone())` or
     /// dereferencing closures for `Copy` types, on `Iterator` or `Option`,
-    /// and suggests `cloned()` or `copied()` instead
+    /// and suggests `cloned()` or `copied()` instead. `cloned()` is suggested for `Copy` types
+    /// as well if `copied()` is not stable in the MSRV.
     ///
     /// **Why is this bad?** Readability, this can be written more concisely
     ///
@@ -42,7 +45,20 @@ declare_clippy_lint! {
     "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"
 }
 
-declare_lint_pass!(MapClone => [MAP_CLONE]);
+pub struct MapClone {
+    msrv: MsrvStack,
+}
+
+impl MapClone {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl_lint_pass!(MapClone => [MAP_CLONE]);
 
 impl<'tcx> LateLintPass<'tcx> for MapClone {
     fn check_expr(&mut self, cx: &LateContext<'_>, e: &hir::Expr<'_>) {
@@ -65,7 +81,7 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
                         hir::BindingAnnotation::Unannotated, .., name, None
                     ) = inner.kind {
                         if ident_eq(name, closure_expr) {
-                            lint(cx, e.span, args[0].span, true);
+                            lint(cx, e.span, &args[0], true, self.msrv.current());
                         }
                     },
                     hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None) => {
@@ -73,7 +89,7 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
                             hir::ExprKind::Unary(hir::UnOp::Deref, ref inner) => {
                                 if ident_eq(name, inner) {
                                     if let ty::Ref(.., Mutability::Not) = cx.typeck_results().expr_ty(inner).kind() {
-                                        lint(cx, e.span, args[0].span, true);
+                                        lint(cx, e.span, &args[0], true, self.msrv.current());
                                     }
                                 }
                             },
@@ -88,7 +104,7 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
                                     if let ty::Ref(_, ty, mutability) = obj_ty.kind() {
                                         if matches!(mutability, Mutability::Not) {
                                             let copy = is_copy(cx, ty);
-                                            lint(cx, e.span, args[0].span, copy);
+                                            lint(cx, e.span, &args[0], copy, self.msrv.current());
                                         }
                                     } else {
                                         lint_needless_cloning(cx, e.span, args[0].span);
@@ -103,6 +119,8 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 fn ident_eq(name: Ident, path: &hir::Expr<'_>) -> bool {
@@ -125,9 +143,15 @@ fn lint_needless_cloning(cx: &LateContext<'_>, root: Span, receiver: Span) {
     )
 }
 
-fn lint(cx: &LateContext<'_>, replace: Span, root: Span, copied: bool) {
+fn lint(cx: &LateContext<'_>, replace: Span, recv: &hir::Expr<'_>, copied: bool, msrv: Option<&RustcVersion>) {
+    let root = recv.span;
+    let copied_path: &[&str] = if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::option_type) {
+        &paths::OPTION_COPIED
+    } else {
+        &paths::ITERATOR_COPIED
+    };
     let mut applicability = Applicability::MachineApplicable;
-    if copied {
+    if copied && std_item_meets_msrv(cx, copied_path, msrv) {
         span_lint_and_sugg(
             cx,
             MAP_CLONE,
@@ -3,10 +3,10 @@ use crate::utils::sugg::Sugg;
 use crate::utils::visitors::LocalUsedVisitor;
 use crate::utils::{
     expr_block, get_parent_expr, implements_trait, in_macro, indent_of, is_allowed, is_expn_of, is_refutable,
-    is_type_diagnostic_item, is_wild, match_qpath, match_type, meets_msrv, multispan_sugg, path_to_local_id,
-    peel_hir_pat_refs, peel_mid_ty_refs, peel_n_hir_expr_refs, remove_blocks, snippet, snippet_block, snippet_opt,
+    is_type_diagnostic_item, is_wild, match_qpath, match_type, multispan_sugg, path_to_local_id, peel_hir_pat_refs,
+    peel_mid_ty_refs, peel_n_hir_expr_refs, remove_blocks, snippet, snippet_block, snippet_opt,
     snippet_with_applicability, span_lint_and_help, span_lint_and_note, span_lint_and_sugg, span_lint_and_then,
-    strip_pat_refs,
+    strip_pat_refs, MsrvStack, StdItem,
 };
 use crate::utils::{paths, search_same, SpanlessEq, SpanlessHash};
 use if_chain::if_chain;
@@ -534,9 +534,9 @@ declare_clippy_lint! {
     "`match` with identical arm bodies"
 }
 
-#[derive(Default)]
 pub struct Matches {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
+    matches_macro: StdItem,
     infallible_destructuring_match_linted: bool,
 }
 
@@ -544,8 +544,9 @@ impl Matches {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
         Self {
-            msrv,
-            ..Matches::default()
+            msrv: MsrvStack::new(msrv),
+            matches_macro: StdItem::new(&paths::MATCHES_MACRO),
+            infallible_destructuring_match_linted: false,
         }
     }
 }
@@ -569,8 +570,6 @@ impl_lint_pass!(Matches => [
     MATCH_SAME_ARMS,
 ]);
 
-const MATCH_LIKE_MATCHES_MACRO_MSRV: RustcVersion = RustcVersion::new(1, 42, 0);
-
 impl<'tcx> LateLintPass<'tcx> for Matches {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if in_external_macro(cx.sess(), expr.span) || in_macro(expr.span) {
@@ -579,7 +578,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
 
         redundant_pattern_match::check(cx, expr);
 
-        if meets_msrv(self.msrv.as_ref(), &MATCH_LIKE_MATCHES_MACRO_MSRV) {
+        if self.matches_macro.meets_msrv(cx, self.msrv.current()) {
             if !check_match_like_matches(cx, expr) {
                 lint_match_arms(cx, expr);
             }
@@ -1,6 +1,6 @@
 use crate::utils::{
-    in_macro, match_def_path, match_qpath, meets_msrv, paths, snippet, snippet_with_applicability, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then,
+    in_macro, match_def_path, match_qpath, paths, snippet, snippet_with_applicability, span_lint_and_help,
+    span_lint_and_sugg, span_lint_and_then, std_item_meets_msrv, MsrvStack,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -225,16 +225,16 @@ fn check_replace_with_default(cx: &LateContext<'_>, src: &Expr<'_>, dest: &Expr<
     }
 }
 
-const MEM_REPLACE_WITH_DEFAULT_MSRV: RustcVersion = RustcVersion::new(1, 40, 0);
-
 pub struct MemReplace {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
 }
 
 impl MemReplace {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
     }
 }
 
@@ -250,7 +250,7 @@ impl<'tcx> LateLintPass<'tcx> for MemReplace {
             then {
                 check_replace_option_with_none(cx, src, dest, expr.span);
                 check_replace_with_uninit(cx, src, dest, expr.span);
-                if meets_msrv(self.msrv.as_ref(), &MEM_REPLACE_WITH_DEFAULT_MSRV) {
+                if std_item_meets_msrv(cx, &paths::MEM_TAKE, self.msrv.current()) {
                     check_replace_with_default(cx, src, dest, expr.span);
                 }
             }
@@ -33,10 +33,10 @@ use crate::utils::usage::mutated_variables;
 use crate::utils::{
     contains_return, contains_ty, get_parent_expr, get_trait_def_id, has_iter_method, higher, implements_trait,
     in_macro, is_copy, is_expn_of, is_type_diagnostic_item, iter_input_pats, last_path_segment, match_def_path,
-    match_qpath, match_trait_method, match_type, meets_msrv, method_calls, method_chain_args, path_to_local_id, paths,
+    match_qpath, match_trait_method, match_type, method_calls, method_chain_args, path_to_local_id, paths,
     remove_blocks, return_ty, single_segment_path, snippet, snippet_with_applicability, snippet_with_macro_callsite,
-    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, strip_pat_refs, sugg, walk_ptrs_ty_depth,
-    SpanlessEq,
+    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, std_item_meets_msrv, strip_pat_refs, sugg,
+    walk_ptrs_ty_depth, MsrvStack, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -1514,13 +1514,15 @@ declare_clippy_lint! {
 }
 
 pub struct Methods {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
 }
 
 impl Methods {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
     }
 }
 
@@ -1600,7 +1602,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["expect", ..] => lint_expect(cx, expr, arg_lists[0]),
             ["unwrap_or", "map"] => option_map_unwrap_or::lint(cx, expr, arg_lists[1], arg_lists[0], method_spans[1]),
             ["unwrap_or_else", "map"] => {
-                if !lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0], self.msrv.as_ref()) {
+                if !lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0], self.msrv.current()) {
                     unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "unwrap_or");
                 }
             },
@@ -1622,7 +1624,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["next", "iter"] => lint_iter_next(cx, expr, arg_lists[1]),
             ["map", "filter"] => lint_filter_map(cx, expr, false),
             ["map", "filter_map"] => lint_filter_map_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["next", "filter_map"] => lint_filter_map_next(cx, expr, arg_lists[1], self.msrv.as_ref()),
+            ["next", "filter_map"] => lint_filter_map_next(cx, expr, arg_lists[1], self.msrv.current()),
             ["map", "find"] => lint_filter_map(cx, expr, true),
             ["flat_map", "filter"] => lint_filter_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
             ["flat_map", "filter_map"] => lint_filter_map_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
@@ -1660,15 +1662,15 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             },
             ["is_file", ..] => lint_filetype_is_file(cx, expr, arg_lists[0]),
             ["map", "as_ref"] => {
-                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false, self.msrv.as_ref())
+                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false, self.msrv.current())
             },
             ["map", "as_mut"] => {
-                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true, self.msrv.as_ref())
+                lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true, self.msrv.current())
             },
             ["unwrap_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "unwrap_or"),
             ["get_or_insert_with", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "get_or_insert"),
             ["ok_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "ok_or"),
-            ["collect", "map"] => lint_map_collect(cx, expr, arg_lists[1], arg_lists[0]),
+            ["collect", "map"] => lint_map_collect(cx, expr, arg_lists[1], arg_lists[0], self.msrv.current()),
             ["for_each", "inspect"] => inspect_for_each::lint(cx, expr, method_spans[1]),
             _ => {},
         }
@@ -2895,8 +2897,6 @@ fn lint_map_flatten<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, map
     }
 }
 
-const MAP_UNWRAP_OR_MSRV: RustcVersion = RustcVersion::new(1, 41, 0);
-
 /// lint use of `map().unwrap_or_else()` for `Option`s and `Result`s
 /// Return true if lint triggered
 fn lint_map_unwrap_or_else<'tcx>(
@@ -2906,7 +2906,7 @@ fn lint_map_unwrap_or_else<'tcx>(
     unwrap_args: &'tcx [hir::Expr<'_>],
     msrv: Option<&RustcVersion>,
 ) -> bool {
-    if !meets_msrv(msrv, &MAP_UNWRAP_OR_MSRV) {
+    if !std_item_meets_msrv(cx, &paths::RESULT_MAP_OR_ELSE, msrv) {
         return false;
     }
     // lint if the caller of `map()` is an `Option`
@@ -3150,8 +3150,6 @@ fn lint_filter_map<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, is_f
     }
 }
 
-const FILTER_MAP_NEXT_MSRV: RustcVersion = RustcVersion::new(1, 30, 0);
-
 /// lint use of `filter_map().next()` for `Iterators`
 fn lint_filter_map_next<'tcx>(
     cx: &LateContext<'tcx>,
@@ -3160,7 +3158,7 @@ fn lint_filter_map_next<'tcx>(
     msrv: Option<&RustcVersion>,
 ) {
     if match_trait_method(cx, expr, &paths::ITERATOR) {
-        if !meets_msrv(msrv, &FILTER_MAP_NEXT_MSRV) {
+        if !std_item_meets_msrv(cx, &paths::ITERATOR_FIND_MAP, msrv) {
             return;
         }
 
@@ -3694,8 +3692,6 @@ fn lint_suspicious_map(cx: &LateContext<'_>, expr: &hir::Expr<'_>) {
     );
 }
 
-const OPTION_AS_REF_DEREF_MSRV: RustcVersion = RustcVersion::new(1, 40, 0);
-
 /// lint use of `_.as_ref().map(Deref::deref)` for `Option`s
 fn lint_option_as_ref_deref<'tcx>(
     cx: &LateContext<'tcx>,
@@ -3705,7 +3701,12 @@ fn lint_option_as_ref_deref<'tcx>(
     is_mut: bool,
     msrv: Option<&RustcVersion>,
 ) {
-    if !meets_msrv(msrv, &OPTION_AS_REF_DEREF_MSRV) {
+    let as_deref_path = if is_mut {
+        &paths::OPTION_AS_DEREF_MUT
+    } else {
+        &paths::OPTION_AS_DEREF
+    };
+    if !std_item_meets_msrv(cx, as_deref_path, msrv) {
         return;
     }
 
@@ -3808,7 +3809,11 @@ fn lint_map_collect(
     expr: &hir::Expr<'_>,
     map_args: &[hir::Expr<'_>],
     collect_args: &[hir::Expr<'_>],
+    msrv: Option<&RustcVersion>,
 ) {
+    if !std_item_meets_msrv(cx, &paths::ITERATOR_TRY_FOR_EACH, msrv) {
+        return;
+    }
     if_chain! {
         // called on Iterator
         if let [map_expr] = collect_args;
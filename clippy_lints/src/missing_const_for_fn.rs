@@ -1,6 +1,6 @@
 use crate::utils::qualify_min_const_fn::is_min_const_fn;
 use crate::utils::{
-    fn_has_unsatisfiable_preds, has_drop, is_entrypoint_fn, meets_msrv, span_lint, trait_ref_of_method,
+    fn_has_unsatisfiable_preds, has_drop, is_entrypoint_fn, meets_msrv, span_lint, trait_ref_of_method, MsrvStack,
 };
 use rustc_hir as hir;
 use rustc_hir::intravisit::FnKind;
@@ -77,13 +77,15 @@ declare_clippy_lint! {
 impl_lint_pass!(MissingConstForFn => [MISSING_CONST_FOR_FN]);
 
 pub struct MissingConstForFn {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
 }
 
 impl MissingConstForFn {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
     }
 }
 
@@ -97,7 +99,7 @@ impl<'tcx> LateLintPass<'tcx> for MissingConstForFn {
         span: Span,
         hir_id: HirId,
     ) {
-        if !meets_msrv(self.msrv.as_ref(), &MISSING_CONST_FOR_FN_MSRV) {
+        if !meets_msrv(self.msrv.current(), &MISSING_CONST_FOR_FN_MSRV) {
             return;
         }
 
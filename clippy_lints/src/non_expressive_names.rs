@@ -1,4 +1,4 @@
-use crate::utils::{span_lint, span_lint_and_then};
+use crate::utils::{span_lint, span_lint_and_then, ConfStack};
 use rustc_ast::ast::{
     Arm, AssocItem, AssocItemKind, Attribute, Block, FnDecl, FnKind, Item, ItemKind, Local, Pat, PatKind,
 };
@@ -67,9 +67,21 @@ declare_clippy_lint! {
     "unclear name"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct NonExpressiveNames {
-    pub single_char_binding_names_threshold: u64,
+    single_char_binding_names_threshold: ConfStack<u64>,
+}
+
+impl NonExpressiveNames {
+    #[must_use]
+    pub fn new(single_char_binding_names_threshold: u64) -> Self {
+        Self {
+            single_char_binding_names_threshold: ConfStack::new(
+                "single_char_binding_names_threshold",
+                single_char_binding_names_threshold,
+            ),
+        }
+    }
 }
 
 impl_lint_pass!(NonExpressiveNames => [SIMILAR_NAMES, MANY_SINGLE_CHAR_NAMES, JUST_UNDERSCORES_AND_DIGITS]);
@@ -93,7 +105,7 @@ struct SimilarNamesLocalVisitor<'a, 'tcx> {
 impl<'a, 'tcx> SimilarNamesLocalVisitor<'a, 'tcx> {
     fn check_single_char_names(&self) {
         let num_single_char_names = self.single_char_names.iter().flatten().count();
-        let threshold = self.lint.single_char_binding_names_threshold;
+        let threshold = *self.lint.single_char_binding_names_threshold.current();
         if num_single_char_names as u64 > threshold {
             let span = self
                 .single_char_names
@@ -380,6 +392,8 @@ impl EarlyLintPass for NonExpressiveNames {
             do_check(self, cx, &item.attrs, &sig.decl, blk);
         }
     }
+
+    extract_conf_attrs!(EarlyContext, single_char_binding_names_threshold);
 }
 
 fn do_check(lint: &mut NonExpressiveNames, cx: &EarlyContext<'_>, attrs: &[Attribute], decl: &FnDecl, blk: &Block) {
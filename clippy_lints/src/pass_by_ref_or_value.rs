@@ -1,6 +1,6 @@
 use std::cmp;
 
-use crate::utils::{is_copy, is_self_ty, snippet, span_lint_and_sugg};
+use crate::utils::{is_copy, is_self_ty, snippet, span_lint_and_sugg, ConfStack};
 use if_chain::if_chain;
 use rustc_ast::attr;
 use rustc_errors::Applicability;
@@ -86,10 +86,10 @@ declare_clippy_lint! {
     "functions taking large arguments by value"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct PassByRefOrValue {
-    ref_min_size: u64,
-    value_max_size: u64,
+    ref_min_size: ConfStack<u64>,
+    value_max_size: ConfStack<u64>,
 }
 
 impl<'tcx> PassByRefOrValue {
@@ -106,8 +106,8 @@ impl<'tcx> PassByRefOrValue {
         });
 
         Self {
-            ref_min_size,
-            value_max_size,
+            ref_min_size: ConfStack::new("trivial_copy_size_limit", ref_min_size),
+            value_max_size: ConfStack::new("pass_by_value_size_limit", value_max_size),
         }
     }
 
@@ -141,7 +141,7 @@ impl<'tcx> PassByRefOrValue {
                         if !output_lts.contains(&input_lt);
                         if is_copy(cx, ty);
                         if let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes());
-                        if size <= self.ref_min_size;
+                        if size <= *self.ref_min_size.current();
                         if let hir::TyKind::Rptr(_, MutTy { ty: ref decl_ty, .. }) = input.kind;
                         then {
                             let value_type = if is_self_ty(decl_ty) {
@@ -153,7 +153,7 @@ impl<'tcx> PassByRefOrValue {
                                 cx,
                                 TRIVIALLY_COPY_PASS_BY_REF,
                                 input.span,
-                                &format!("this argument ({} byte) is passed by reference, but would be more efficient if passed by value (limit: {} byte)", size, self.ref_min_size),
+                                &format!("this argument ({} byte) is passed by reference, but would be more efficient if passed by value (limit: {} byte)", size, self.ref_min_size.current()),
                                 "consider passing by value instead",
                                 value_type,
                                 Applicability::Unspecified,
@@ -176,13 +176,13 @@ impl<'tcx> PassByRefOrValue {
                         if is_copy(cx, ty);
                         if !is_self_ty(input);
                         if let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes());
-                        if size > self.value_max_size;
+                        if size > *self.value_max_size.current();
                         then {
                             span_lint_and_sugg(
                                 cx,
                                 LARGE_TYPES_PASSED_BY_VALUE,
                                 input.span,
-                                &format!("this argument ({} byte) is passed by value, but might be more efficient if passed by reference (limit: {} byte)", size, self.value_max_size),
+                                &format!("this argument ({} byte) is passed by value, but might be more efficient if passed by reference (limit: {} byte)", size, self.value_max_size.current()),
                                 "consider passing by reference instead",
                                 format!("&{}", snippet(cx, input.span, "_")),
                                 Applicability::MaybeIncorrect,
@@ -254,4 +254,6 @@ impl<'tcx> LateLintPass<'tcx> for PassByRefOrValue {
 
         self.check_poly_fn(cx, hir_id, decl, Some(span));
     }
+
+    extract_conf_attrs!(LateContext, ref_min_size, value_max_size);
 }
@@ -1,4 +1,4 @@
-use crate::utils::{in_macro, snippet, snippet_with_applicability, span_lint_and_help, SpanlessHash};
+use crate::utils::{in_macro, snippet, snippet_with_applicability, span_lint_and_help, ConfStack, SpanlessHash};
 use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
@@ -59,15 +59,17 @@ declare_clippy_lint! {
     "Check if the same trait bounds are specified twice during a function declaration"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct TraitBounds {
-    max_trait_bounds: u64,
+    max_trait_bounds: ConfStack<u64>,
 }
 
 impl TraitBounds {
     #[must_use]
     pub fn new(max_trait_bounds: u64) -> Self {
-        Self { max_trait_bounds }
+        Self {
+            max_trait_bounds: ConfStack::new("max_trait_bounds", max_trait_bounds),
+        }
     }
 }
 
@@ -78,6 +80,8 @@ impl<'tcx> LateLintPass<'tcx> for TraitBounds {
         self.check_type_repetition(cx, gen);
         check_trait_bound_duplication(cx, gen);
     }
+
+    extract_conf_attrs!(LateContext, max_trait_bounds);
 }
 
 fn get_trait_res_span_from_bound(bound: &GenericBound<'_>) -> Option<(Res, Span)> {
@@ -89,7 +93,7 @@ fn get_trait_res_span_from_bound(bound: &GenericBound<'_>) -> Option<(Res, Span)
 }
 
 impl TraitBounds {
-    fn check_type_repetition(self, cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
+    fn check_type_repetition(&self, cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
         if in_macro(gen.span) {
             return;
         }
@@ -103,7 +107,7 @@ impl TraitBounds {
         for bound in gen.where_clause.predicates {
             if_chain! {
                 if let WherePredicate::BoundPredicate(ref p) = bound;
-                if p.bounds.len() as u64 <= self.max_trait_bounds;
+                if p.bounds.len() as u64 <= *self.max_trait_bounds.current();
                 if !in_macro(p.span);
                 let h = hash(&p.bounded_ty);
                 if let Some(ref v) = map.insert(h, p.bounds.iter().collect::<Vec<_>>());
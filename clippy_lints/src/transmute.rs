@@ -1,14 +1,15 @@
 use crate::utils::{
     in_constant, is_normalizable, last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_sugg,
-    span_lint_and_then, sugg,
+    span_lint_and_then, std_item_meets_msrv, sugg, MsrvStack,
 };
 use if_chain::if_chain;
 use rustc_ast as ast;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, GenericArg, Mutability, QPath, TyKind, UnOp};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::ty::{self, cast::CastKind, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::DUMMY_SP;
 use rustc_typeck::check::{cast::CastCheck, FnCtxt, Inherited};
 use std::borrow::Cow;
@@ -301,7 +302,20 @@ declare_clippy_lint! {
     "transmute between collections of layout-incompatible types"
 }
 
-declare_lint_pass!(Transmute => [
+pub struct Transmute {
+    msrv: MsrvStack,
+}
+
+impl Transmute {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl_lint_pass!(Transmute => [
     CROSSPOINTER_TRANSMUTE,
     TRANSMUTE_PTR_TO_REF,
     TRANSMUTE_PTR_TO_PTR,
@@ -583,6 +597,14 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                         e.span,
                         &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
                         |diag| {
+                            let to_bits = match float_ty {
+                                ty::FloatTy::F32 => &paths::F32_TO_BITS,
+                                ty::FloatTy::F64 => &paths::F64_TO_BITS,
+                            };
+                            if !std_item_meets_msrv(cx, to_bits, self.msrv.current()) {
+                                return;
+                            }
+
                             let mut expr = &args[0];
                             let mut arg = sugg::Sugg::hir(cx, expr, "..");
 
@@ -663,6 +685,8 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 /// Gets the snippet of `Bar` in `…::transmute<Foo, &Bar>`. If that snippet is
@@ -33,10 +33,10 @@ use crate::utils::paths;
 use crate::utils::sugg::Sugg;
 use crate::utils::{
     clip, comparisons, differing_macro_contexts, higher, in_constant, indent_of, int_bits, is_hir_ty_cfg_dependant,
-    is_type_diagnostic_item, last_path_segment, match_def_path, match_path, meets_msrv, method_chain_args,
-    multispan_sugg, numeric_literal::NumericLiteral, reindent_multiline, sext, snippet, snippet_opt,
-    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg,
-    span_lint_and_then, unsext,
+    is_type_diagnostic_item, last_path_segment, match_def_path, match_path, method_chain_args, multispan_sugg,
+    numeric_literal::NumericLiteral, reindent_multiline, sext, snippet, snippet_opt, snippet_with_applicability,
+    snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, unsext,
+    ConfStack, MsrvStack, StdItem,
 };
 
 declare_clippy_lint! {
@@ -251,7 +251,7 @@ declare_clippy_lint! {
 }
 
 pub struct Types {
-    vec_box_size_threshold: u64,
+    vec_box_size_threshold: ConfStack<u64>,
 }
 
 impl_lint_pass!(Types => [BOX_VEC, VEC_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION, RC_BUFFER]);
@@ -285,6 +285,8 @@ impl<'tcx> LateLintPass<'tcx> for Types {
             self.check_ty(cx, ty, true);
         }
     }
+
+    extract_conf_attrs!(LateContext, vec_box_size_threshold);
 }
 
 /// Checks if `qpath` has last segment with type parameter matching `path`
@@ -339,7 +341,9 @@ fn match_borrows_parameter(_cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<S
 
 impl Types {
     pub fn new(vec_box_size_threshold: u64) -> Self {
-        Self { vec_box_size_threshold }
+        Self {
+            vec_box_size_threshold: ConfStack::new("vec_box_size_threshold", vec_box_size_threshold),
+        }
     }
 
     fn check_fn_decl(&mut self, cx: &LateContext<'_>, decl: &FnDecl<'_>) {
@@ -548,7 +552,7 @@ impl Types {
                             if !ty_ty.has_escaping_bound_vars();
                             if ty_ty.is_sized(cx.tcx.at(ty.span), cx.param_env);
                             if let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes());
-                            if ty_ty_size <= self.vec_box_size_threshold;
+                            if ty_ty_size <= *self.vec_box_size_threshold.current();
                             then {
                                 span_lint_and_sugg(
                                     cx,
@@ -1897,13 +1901,15 @@ declare_clippy_lint! {
 }
 
 pub struct TypeComplexity {
-    threshold: u64,
+    threshold: ConfStack<u64>,
 }
 
 impl TypeComplexity {
     #[must_use]
     pub fn new(threshold: u64) -> Self {
-        Self { threshold }
+        Self {
+            threshold: ConfStack::new("type_complexity_threshold", threshold),
+        }
     }
 }
 
@@ -1957,6 +1963,8 @@ impl<'tcx> LateLintPass<'tcx> for TypeComplexity {
             self.check_type(cx, ty);
         }
     }
+
+    extract_conf_attrs!(LateContext, threshold);
 }
 
 impl<'tcx> TypeComplexity {
@@ -1979,7 +1987,7 @@ impl<'tcx> TypeComplexity {
             visitor.score
         };
 
-        if score > self.threshold {
+        if score > *self.threshold.current() {
             span_lint(
                 cx,
                 TYPE_COMPLEXITY,
@@ -2888,8 +2896,6 @@ impl<'tcx> LateLintPass<'tcx> for RefToMut {
     }
 }
 
-const PTR_AS_PTR_MSRV: RustcVersion = RustcVersion::new(1, 38, 0);
-
 declare_clippy_lint! {
     /// **What it does:**
     /// Checks for `as` casts between raw pointers without changing its mutability,
@@ -2922,13 +2928,17 @@ declare_clippy_lint! {
 }
 
 pub struct PtrAsPtr {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
+    ptr_cast: StdItem,
 }
 
 impl PtrAsPtr {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
-        Self { msrv }
+        Self {
+            msrv: MsrvStack::new(msrv),
+            ptr_cast: StdItem::new(&paths::PTR_CAST),
+        }
     }
 }
 
@@ -2936,7 +2946,7 @@ impl_lint_pass!(PtrAsPtr => [PTR_AS_PTR]);
 
 impl<'tcx> LateLintPass<'tcx> for PtrAsPtr {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &PTR_AS_PTR_MSRV) {
+        if !self.ptr_cast.meets_msrv(cx, self.msrv.current()) {
             return;
         }
 
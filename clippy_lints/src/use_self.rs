@@ -1,4 +1,4 @@
-use crate::utils::{in_macro, meets_msrv, snippet_opt, span_lint_and_sugg};
+use crate::utils::{in_macro, meets_msrv, snippet_opt, span_lint_and_sugg, MsrvStack};
 use if_chain::if_chain;
 
 use rustc_errors::Applicability;
@@ -57,7 +57,7 @@ declare_clippy_lint! {
 
 #[derive(Default)]
 pub struct UseSelf {
-    msrv: Option<RustcVersion>,
+    msrv: MsrvStack,
     stack: Vec<StackItem>,
 }
 
@@ -67,7 +67,7 @@ impl UseSelf {
     #[must_use]
     pub fn new(msrv: Option<RustcVersion>) -> Self {
         Self {
-            msrv,
+            msrv: MsrvStack::new(msrv),
             ..Self::default()
         }
     }
@@ -235,7 +235,7 @@ impl<'tcx> LateLintPass<'tcx> for UseSelf {
     }
 
     fn check_ty(&mut self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) {
-        if in_macro(hir_ty.span) | in_impl(cx, hir_ty) | !meets_msrv(self.msrv.as_ref(), &USE_SELF_MSRV) {
+        if in_macro(hir_ty.span) | in_impl(cx, hir_ty) | !meets_msrv(self.msrv.current(), &USE_SELF_MSRV) {
             return;
         }
 
@@ -282,7 +282,7 @@ impl<'tcx> LateLintPass<'tcx> for UseSelf {
             }
         }
 
-        if in_macro(expr.span) | !meets_msrv(self.msrv.as_ref(), &USE_SELF_MSRV) {
+        if in_macro(expr.span) | !meets_msrv(self.msrv.current(), &USE_SELF_MSRV) {
             return;
         }
 
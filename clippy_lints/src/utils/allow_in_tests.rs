@@ -0,0 +1,210 @@
+//! Finds the test code, where the lints of the `allow-in-tests` configuration are not reported:
+//! the `#[test]` functions, the `#[cfg(test)]` items and the doctests.
+//!
+//! The test code is collected from the crate before and after its expansion, so that it is known
+//! before any lint is emitted: the pre-expansion crate still has the `#[test]` attributes, which
+//! the expansion replaces with a `#[rustc_test_marker]` constant of the same name, while the
+//! expanded crate has the contents of the modules in other files.
+//!
+//! An item with a `#[clippy::conf(allow_in_tests(..))]` attribute sets the lints allowed in the
+//! test code under it.
+
+use crate::utils::attrs::conf_attr_scopes;
+use crate::utils::conf::{closest_key, Conf};
+use rustc_ast::ast::{self, AttrKind, Attribute, Item, ItemKind, NestedMetaItem};
+use rustc_ast::ptr::P;
+use rustc_ast::visit::{self, AssocCtxt, Visitor};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::{EarlyContext, EarlyLintPass, Lint, LintStore};
+use rustc_session::{declare_lint_pass, Session};
+use rustc_span::{sym, BytePos, Span};
+use std::env;
+use std::lazy::SyncLazy;
+use std::ops::Range;
+use std::sync::Mutex;
+
+#[derive(Default)]
+struct TestCode {
+    /// The lints allowed in the test code, by name without the `clippy::` prefix.
+    allowed: FxHashSet<String>,
+    /// The lints allowed in the test code of the items with a `#[clippy::conf(allow_in_tests(..))]`
+    /// attribute, the inner items last.
+    scopes: Vec<(Range<BytePos>, FxHashSet<String>)>,
+    /// Whether the crate is a doctest, all of which is test code.
+    doctest: bool,
+    /// The test code, as the ranges of the spans of the items, since spans are not `Send`.
+    ranges: Vec<Range<BytePos>>,
+}
+
+static TEST_CODE: SyncLazy<Mutex<TestCode>> = SyncLazy::new(Mutex::default);
+
+/// Reads the lints allowed in the test code from `conf`.
+pub fn read(conf: &Conf) {
+    *TEST_CODE.lock().unwrap() = TestCode {
+        allowed: lint_names(&conf.allow_in_tests),
+        // set by rustdoc when it compiles a doctest
+        doctest: env::var_os("UNSTABLE_RUSTDOC_TEST_PATH").is_some(),
+        scopes: Vec::new(),
+        ranges: Vec::new(),
+    };
+}
+
+fn lint_names(names: &[String]) -> FxHashSet<String> {
+    names
+        .iter()
+        .map(|name| name.trim_start_matches("clippy::").replace('-', "_"))
+        .collect()
+}
+
+/// Warns about the lints allowed in the test code which are not registered Clippy lints.
+pub fn check_lint_names(sess: &Session, store: &LintStore) {
+    let known = || {
+        store
+            .get_lints()
+            .iter()
+            .filter_map(|lint| lint.name_lower().strip_prefix("clippy::").map(ToString::to_string))
+    };
+    let test_code = TEST_CODE.lock().unwrap();
+    let mut unknown: Vec<_> = test_code
+        .allowed
+        .iter()
+        .filter(|name| !known().any(|known| known == **name))
+        .collect();
+    unknown.sort();
+    for name in unknown {
+        let mut warning = sess.struct_warn(&format!(
+            "unknown lint `clippy::{}` in the `allow-in-tests` configuration",
+            name
+        ));
+        if let Some(help) = closest_key(name, known()) {
+            warning.help(&help);
+        }
+        warning.emit();
+    }
+}
+
+/// Whether `span`, or its macro call site, is in test code.
+pub fn is_in_test_code(span: Span) -> bool {
+    let test_code = TEST_CODE.lock().unwrap();
+    test_code.doctest || test_code.ranges.iter().any(|range| contains(range, span))
+}
+
+/// Whether `range` contains `span`, or its macro call site.
+fn contains(range: &Range<BytePos>, span: Span) -> bool {
+    let contains = |span: Span| range.start <= span.lo() && span.hi() <= range.end;
+    contains(span) || contains(span.source_callsite())
+}
+
+/// Whether `lint` is reported at `span`, which is the case unless it is allowed in the test code
+/// and `span` is in test code.
+pub fn is_reported(lint: &'static Lint, span: Span) -> bool {
+    let is_allowed = {
+        let test_code = TEST_CODE.lock().unwrap();
+        let allowed = test_code
+            .scopes
+            .iter()
+            .rev()
+            .find(|(range, _)| contains(range, span))
+            .map_or(&test_code.allowed, |(_, allowed)| allowed);
+        !allowed.is_empty() && allowed.contains(lint.name_lower().trim_start_matches("clippy::"))
+    };
+    !(is_allowed && is_in_test_code(span))
+}
+
+/// Whether `attr` is `#[cfg(test)]`, or `#[cfg(all(test, ..))]`.
+fn is_cfg_test(attr: &Attribute) -> bool {
+    fn requires_test(item: &NestedMetaItem) -> bool {
+        item.has_name(sym::test)
+            || (item.has_name(sym::all)
+                && item
+                    .meta_item_list()
+                    .map_or(false, |items| items.iter().any(requires_test)))
+    }
+    attr.has_name(sym::cfg)
+        && attr
+            .meta_item_list()
+            .map_or(false, |items| items.iter().any(requires_test))
+}
+
+/// Whether `attr` is `#[test]`, or a test attribute of another crate like `#[tokio::test]`.
+fn is_test_attr(attr: &Attribute) -> bool {
+    match &attr.kind {
+        AttrKind::Normal(item, _) => item
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident.name == sym::test),
+        AttrKind::DocComment(..) => false,
+    }
+}
+
+declare_lint_pass!(TestCodeCollector => []);
+
+impl EarlyLintPass for TestCodeCollector {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        let mut visitor = TestCodeVisitor { spans: Vec::new() };
+        visitor.add_test_fns(&krate.module.items);
+        visit::walk_crate(&mut visitor, krate);
+        let scopes = conf_attr_scopes::<Vec<String>>(cx.sess, krate, "allow_in_tests");
+        let mut test_code = TEST_CODE.lock().unwrap();
+        test_code
+            .ranges
+            .extend(visitor.spans.into_iter().map(|span| span.lo()..span.hi()));
+        // replaced rather than extended, as the expanded crate has all the items of the other one
+        test_code.scopes = scopes
+            .into_iter()
+            .map(|(range, names)| (range, lint_names(&names)))
+            .collect();
+    }
+}
+
+struct TestCodeVisitor {
+    spans: Vec<Span>,
+}
+
+impl TestCodeVisitor {
+    /// Adds the functions of `items` marked by a `#[rustc_test_marker]` constant.
+    fn add_test_fns(&mut self, items: &[P<Item>]) {
+        let test_names: FxHashSet<_> = items
+            .iter()
+            .filter(|item| {
+                matches!(item.kind, ItemKind::Const(..))
+                    && item.attrs.iter().any(|attr| attr.has_name(sym::rustc_test_marker))
+            })
+            .map(|item| item.ident.name)
+            .collect();
+        if test_names.is_empty() {
+            return;
+        }
+        self.spans.extend(
+            items
+                .iter()
+                .filter(|item| matches!(item.kind, ItemKind::Fn(..)) && test_names.contains(&item.ident.name))
+                .map(|item| item.span),
+        );
+    }
+}
+
+impl<'ast> Visitor<'ast> for TestCodeVisitor {
+    fn visit_item(&mut self, item: &'ast Item) {
+        if item.attrs.iter().any(|attr| is_cfg_test(attr) || is_test_attr(attr)) {
+            self.spans.push(item.span);
+            if let ItemKind::Mod(module) = &item.kind {
+                self.spans.push(module.inner);
+            }
+            return;
+        }
+        if let ItemKind::Mod(module) = &item.kind {
+            self.add_test_fns(&module.items);
+        }
+        visit::walk_item(self, item);
+    }
+
+    fn visit_assoc_item(&mut self, item: &'ast ast::AssocItem, ctxt: AssocCtxt) {
+        if item.attrs.iter().any(is_cfg_test) {
+            self.spans.push(item.span);
+            return;
+        }
+        visit::walk_assoc_item(self, item, ctxt);
+    }
+}
@@ -1,7 +1,14 @@
+use super::conf::check_override;
+use super::parse_msrv;
 use rustc_ast::ast;
+use rustc_ast::visit::{self, AssocCtxt, Visitor};
 use rustc_errors::Applicability;
+use rustc_semver::RustcVersion;
 use rustc_session::Session;
-use rustc_span::sym;
+use rustc_span::{sym, BytePos, Span};
+use serde::de::DeserializeOwned;
+use std::convert::TryFrom;
+use std::ops::Range;
 use std::str::FromStr;
 
 /// Deprecation status of attributes known by Clippy.
@@ -17,6 +24,7 @@ pub enum DeprecationStatus {
 pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ("author", DeprecationStatus::None),
     ("cognitive_complexity", DeprecationStatus::None),
+    ("conf", DeprecationStatus::None),
     (
         "cyclomatic_complexity",
         DeprecationStatus::Replaced("cognitive_complexity"),
@@ -51,6 +59,257 @@ impl LimitStack {
         let stack = &mut self.stack;
         parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
     }
+    /// Like `push_attrs`, for the limit set by `#[clippy::conf(key = value)]`.
+    pub fn push_conf_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], key: &'static str) {
+        if let Some(value) = conf_attr_value(sess, attrs, key) {
+            let limit = value
+                .ok()
+                .and_then(|value| value.try_into().ok())
+                .unwrap_or_else(|| self.limit());
+            self.stack.push(limit);
+        }
+    }
+    pub fn pop_conf_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], key: &'static str) {
+        if conf_attr_value(sess, attrs, key).is_some() {
+            self.stack.pop();
+        }
+    }
+}
+
+/// The MSRVs set by the `clippy::msrv` attributes in scope, the innermost one last. The bottom
+/// of the stack is the MSRV of the configuration.
+#[derive(Clone)]
+pub struct MsrvStack {
+    stack: Vec<Option<RustcVersion>>,
+}
+
+impl Default for MsrvStack {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl MsrvStack {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { stack: vec![msrv] }
+    }
+    pub fn current(&self) -> Option<&RustcVersion> {
+        self.stack
+            .last()
+            .expect("there should always be a value in the stack")
+            .as_ref()
+    }
+    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) {
+        let mut msrv_attrs = get_attr(sess, attrs, "msrv");
+        let attr = match msrv_attrs.next() {
+            Some(attr) => attr,
+            None => return,
+        };
+        for duplicate in msrv_attrs {
+            sess.struct_span_err(duplicate.span, "`msrv` is defined multiple times")
+                .span_note(attr.span, "first definition found here")
+                .emit();
+        }
+        let msrv = attr.value_str().map_or_else(
+            || {
+                sess.span_err(attr.span, "bad clippy attribute");
+                None
+            },
+            |msrv| parse_msrv(&msrv.as_str(), Some(sess), Some(attr.span)),
+        );
+        // An invalid attribute keeps the MSRV of the enclosing scope.
+        let msrv = msrv.or_else(|| self.current().copied());
+        self.stack.push(msrv);
+    }
+    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) {
+        if get_attr(sess, attrs, "msrv").next().is_some() {
+            self.stack.pop();
+        }
+    }
+}
+
+/// The values of a configuration key set by the `clippy::conf` attributes in scope, the innermost
+/// one last. The bottom of the stack is the value of the configuration file.
+#[derive(Clone, Debug)]
+pub struct ConfStack<T> {
+    key: &'static str,
+    stack: Vec<T>,
+}
+
+impl<T: Clone + DeserializeOwned> ConfStack<T> {
+    #[must_use]
+    pub fn new(key: &'static str, value: T) -> Self {
+        Self {
+            key,
+            stack: vec![value],
+        }
+    }
+    pub fn current(&self) -> &T {
+        self.stack.last().expect("there should always be a value in the stack")
+    }
+    /// Pushes the value `attrs` set, returning whether they set one.
+    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) -> bool {
+        let value = match conf_attr_value(sess, attrs, self.key) {
+            Some(value) => value,
+            None => return false,
+        };
+        // An invalid value, reported by `check_conf_attr`, keeps the value of the enclosing scope.
+        let value = value
+            .ok()
+            .and_then(|value| value.try_into().ok())
+            .unwrap_or_else(|| self.current().clone());
+        self.stack.push(value);
+        true
+    }
+    /// Pops the value `attrs` set, returning whether they set one.
+    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) -> bool {
+        let is_set = conf_attr_value(sess, attrs, self.key).is_some();
+        if is_set {
+            self.stack.pop();
+        }
+        is_set
+    }
+}
+
+/// The values the `clippy::conf` attributes of `krate` set for `key`, along with the code they
+/// apply to, for the configurations read from the spans of the diagnostics rather than by a lint
+/// pass. The attributes of the crate apply to all of it, the ones of an item to the item and to the
+/// file of its module. The outer scopes come first.
+pub fn conf_attr_scopes<T: Clone + DeserializeOwned>(
+    sess: &Session,
+    krate: &ast::Crate,
+    key: &'static str,
+) -> Vec<(Range<BytePos>, T)> {
+    let mut visitor = ConfScopeVisitor {
+        sess,
+        key,
+        scopes: Vec::new(),
+    };
+    visitor.add(&krate.attrs, &[BytePos(0)..BytePos(u32::MAX)]);
+    visit::walk_crate(&mut visitor, krate);
+    visitor.scopes
+}
+
+struct ConfScopeVisitor<'a, T> {
+    sess: &'a Session,
+    key: &'static str,
+    scopes: Vec<(Range<BytePos>, T)>,
+}
+
+impl<T: Clone + DeserializeOwned> ConfScopeVisitor<'_, T> {
+    fn add(&mut self, attrs: &[ast::Attribute], ranges: &[Range<BytePos>]) {
+        // An invalid value is reported by `check_conf_attr`.
+        if let Some(Ok(value)) = conf_attr_value(self.sess, attrs, self.key) {
+            if let Ok(value) = value.try_into::<T>() {
+                self.scopes
+                    .extend(ranges.iter().map(|range| (range.clone(), value.clone())));
+            }
+        }
+    }
+}
+
+fn span_range(span: Span) -> Range<BytePos> {
+    span.lo()..span.hi()
+}
+
+impl<'ast, T: Clone + DeserializeOwned> Visitor<'ast> for ConfScopeVisitor<'_, T> {
+    fn visit_item(&mut self, item: &'ast ast::Item) {
+        match &item.kind {
+            ast::ItemKind::Mod(module) => self.add(&item.attrs, &[span_range(item.span), span_range(module.inner)]),
+            _ => self.add(&item.attrs, &[span_range(item.span)]),
+        }
+        visit::walk_item(self, item);
+    }
+
+    fn visit_assoc_item(&mut self, item: &'ast ast::AssocItem, ctxt: AssocCtxt) {
+        self.add(&item.attrs, &[span_range(item.span)]);
+        visit::walk_assoc_item(self, item, ctxt);
+    }
+}
+
+/// The value the `clippy::conf` attributes among `attrs` set for `key`, if they set one.
+fn conf_attr_value(sess: &Session, attrs: &[ast::Attribute], key: &str) -> Option<Result<toml::Value, Span>> {
+    get_attr(sess, attrs, "conf")
+        .filter_map(ast::Attribute::meta_item_list)
+        .flatten()
+        .filter_map(|item| item.meta_item().cloned())
+        .filter(|item| item.ident().map_or(false, |ident| ident.name.as_str() == key))
+        .last()
+        .map(|item| conf_entry_value(&item))
+}
+
+/// The value set by an entry of a `clippy::conf` attribute, like `key = 1` or `key("a", "b")`.
+/// The error is the span of what can't be converted.
+fn conf_entry_value(item: &ast::MetaItem) -> Result<toml::Value, Span> {
+    match &item.kind {
+        ast::MetaItemKind::NameValue(lit) => lit_value(lit),
+        ast::MetaItemKind::List(items) => items
+            .iter()
+            .map(|item| match item {
+                ast::NestedMetaItem::Literal(lit) => lit_value(lit),
+                ast::NestedMetaItem::MetaItem(item) => Err(item.span),
+            })
+            .collect::<Result<_, _>>()
+            .map(toml::Value::Array),
+        ast::MetaItemKind::Word => Err(item.span),
+    }
+}
+
+fn lit_value(lit: &ast::Lit) -> Result<toml::Value, Span> {
+    match lit.kind {
+        ast::LitKind::Int(value, _) => i64::try_from(value).map(toml::Value::Integer).map_err(|_| lit.span),
+        ast::LitKind::Str(value, _) => Ok(toml::Value::String(value.to_string())),
+        ast::LitKind::Bool(value) => Ok(toml::Value::Boolean(value)),
+        _ => Err(lit.span),
+    }
+}
+
+/// Reports the errors of a `#[clippy::conf(..)]` attribute: the keys which don't exist or can't
+/// be set by an attribute, and the values which don't fit their key.
+pub fn check_conf_attr(sess: &Session, attr: &ast::Attribute) {
+    let is_conf = match &attr.kind {
+        ast::AttrKind::Normal(item, _) => {
+            let segments = &item.path.segments;
+            segments.len() == 2 && segments[0].ident.name == sym::clippy && segments[1].ident.name.as_str() == "conf"
+        },
+        ast::AttrKind::DocComment(..) => false,
+    };
+    if !is_conf {
+        return;
+    }
+    let items = if let Some(items) = attr.meta_item_list() {
+        items
+    } else {
+        sess.span_err(attr.span, "expected a list like `#[clippy::conf(key = value)]`");
+        return;
+    };
+    for item in &items {
+        let (key, value) = match item.meta_item() {
+            Some(meta) if !meta.is_word() => match (meta.ident(), conf_entry_value(meta)) {
+                (Some(key), Ok(value)) => (key, value),
+                (None, _) => {
+                    sess.span_err(meta.path.span, "expected a configuration key");
+                    continue;
+                },
+                (_, Err(span)) => {
+                    sess.span_err(span, "expected an integer, a string, a boolean, or a list of them");
+                    continue;
+                },
+            },
+            _ => {
+                sess.span_err(item.span(), "expected `key = value` or `key(values...)`");
+                continue;
+            },
+        };
+        if let Err((msg, help)) = check_override(&key.name.as_str(), value) {
+            let mut diag = sess.struct_span_err(item.span(), &msg);
+            if let Some(help) = help {
+                diag.help(&help);
+            }
+            diag.emit();
+        }
+    }
 }
 
 pub fn get_attr<'a>(
@@ -125,24 +384,6 @@ fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'
     }
 }
 
-pub fn get_unique_inner_attr(sess: &Session, attrs: &[ast::Attribute], name: &'static str) -> Option<ast::Attribute> {
-    let mut unique_attr = None;
-    for attr in get_attr(sess, attrs, name) {
-        match attr.style {
-            ast::AttrStyle::Inner if unique_attr.is_none() => unique_attr = Some(attr.clone()),
-            ast::AttrStyle::Inner => {
-                sess.struct_span_err(attr.span, &format!("`{}` is defined multiple times", name))
-                    .span_note(unique_attr.as_ref().unwrap().span, "first definition found here")
-                    .emit();
-            },
-            ast::AttrStyle::Outer => {
-                sess.span_err(attr.span, &format!("`{}` cannot be an outer attribute", name));
-            },
-        }
-    }
-    unique_attr
-}
-
 /// Return true if the attributes contain any of `proc_macro`,
 /// `proc_macro_derive` or `proc_macro_attribute`, false otherwise
 pub fn is_proc_macro(sess: &Session, attrs: &[ast::Attribute]) -> bool {
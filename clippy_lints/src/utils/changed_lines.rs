@@ -0,0 +1,262 @@
+//! Restricts the Clippy diagnostics to the lines changed since a git revision, for
+//! `cargo clippy --changed-since <REV>`.
+//!
+//! The changes are read from `git diff` once per crate. Files that git doesn't track count as
+//! changed as a whole. With `--changed-items`, the changes are widened to the innermost items
+//! containing them, so that lints about a changed item are reported wherever they point in it.
+
+use rustc_ast::ast;
+use rustc_ast::visit::{self, AssocCtxt, Visitor};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
+use rustc_session::{declare_lint_pass, Session};
+use rustc_span::source_map::SourceMap;
+use rustc_span::{FileName, Span};
+use std::env;
+use std::lazy::SyncLazy;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+/// The changed lines of each file, by canonical path, as ranges of 1-based line numbers.
+type Changes = FxHashMap<PathBuf, Vec<RangeInclusive<usize>>>;
+
+/// The changes the diagnostics are restricted to, if any.
+static CHANGES: SyncLazy<Mutex<Option<Changed>>> = SyncLazy::new(|| Mutex::new(None));
+
+/// The changed lines, with the canonical paths of the source files cached, as they are looked up
+/// for every diagnostic, even the ones of allowed lints.
+struct Changed {
+    lines: Changes,
+    paths: FxHashMap<FileName, Option<PathBuf>>,
+}
+
+impl Changed {
+    /// The changed lines of the source file `name`, if it has changed.
+    fn file_lines(&mut self, name: &FileName) -> Option<&mut Vec<RangeInclusive<usize>>> {
+        let path = self.paths.entry(name.clone()).or_insert_with(|| path(name));
+        self.lines.get_mut(path.as_ref()?)
+    }
+}
+
+/// Restricts the diagnostics to the lines changed since `rev`.
+pub fn read(sess: &Session, rev: &str) {
+    match changes(rev) {
+        Ok(lines) => {
+            *CHANGES.lock().unwrap() = Some(Changed {
+                lines,
+                paths: FxHashMap::default(),
+            })
+        },
+        Err(error) => sess.err(&format!("could not read the changes since `{}`: {}", rev, error)),
+    }
+}
+
+/// Runs git with `args` in the current directory, returning its output.
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|error| format!("could not run git: {}", error))?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).map_err(|error| error.to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Reads the lines changed since `rev` in the working tree.
+fn changes(rev: &str) -> Result<Changes, String> {
+    let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+    let root = root.canonicalize().unwrap_or(root);
+
+    // the prefixes are set, as `parse_diff` expects them, whatever `diff.noprefix` and
+    // `diff.mnemonicPrefix` are
+    let diff = git(&[
+        "diff",
+        "--no-ext-diff",
+        "--no-color",
+        "--unified=0",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+        rev,
+        "--",
+    ])?;
+    let mut changes = parse_diff(&root, &diff);
+
+    let untracked = git(&["ls-files", "--others", "--exclude-standard", "--full-name"])?;
+    for file in untracked.lines() {
+        changes.insert(root.join(file), vec![1..=usize::MAX]);
+    }
+    Ok(changes)
+}
+
+/// Reads the changed lines of each file from a diff without context lines, with paths relative
+/// to `root`.
+#[allow(clippy::range_minus_one)]
+fn parse_diff(root: &Path, diff: &str) -> Changes {
+    let mut changes = Changes::default();
+    let mut lines = None;
+    for line in diff.lines() {
+        if let Some(file) = line.strip_prefix("+++ ") {
+            // deleted files are `/dev/null`
+            lines = file
+                .strip_prefix("b/")
+                .map(|file| changes.entry(root.join(file)).or_insert_with(Vec::new));
+        } else if let (Some(lines), Some(hunk)) = (&mut lines, line.strip_prefix("@@ ")) {
+            // `@@ -<start>[,<len>] +<start>[,<len>] @@`, the length defaults to 1
+            let new = hunk.split(' ').find_map(|range| range.strip_prefix('+'));
+            let mut new = new.unwrap_or_default().split(',').map(str::parse::<usize>);
+            match (new.next(), new.next()) {
+                // removed lines are between the line `start` and the next one
+                (Some(Ok(start)), Some(Ok(0))) => lines.push(start.max(1)..=start + 1),
+                (Some(Ok(start)), Some(Ok(len))) => lines.push(start..=start + len - 1),
+                (Some(Ok(start)), None) => lines.push(start..=start),
+                _ => {},
+            }
+        }
+    }
+    changes
+}
+
+/// The canonical path of a source file, if it is on disk.
+fn path(name: &FileName) -> Option<PathBuf> {
+    match name {
+        FileName::Real(name) => env::current_dir().ok()?.join(name.local_path()).canonicalize().ok(),
+        _ => None,
+    }
+}
+
+/// The file of `span` and the lines it covers.
+fn lines(sm: &SourceMap, span: Span) -> (FileName, RangeInclusive<usize>) {
+    let lo = sm.lookup_char_pos(span.lo());
+    let hi = sm.lookup_char_pos(span.hi());
+    (lo.file.name.clone(), lo.line..=hi.line.max(lo.line))
+}
+
+fn overlaps(a: &RangeInclusive<usize>, b: &RangeInclusive<usize>) -> bool {
+    a.start() <= b.end() && b.start() <= a.end()
+}
+
+/// Whether a diagnostic at `span` is reported, which is the case unless the diagnostics are
+/// restricted to the changed lines and neither `span` nor its macro call site is on one of them.
+pub fn is_reported(sm: &SourceMap, span: Span) -> bool {
+    let mut changes = CHANGES.lock().unwrap();
+    let changes = match &mut *changes {
+        Some(changes) => changes,
+        None => return true,
+    };
+    let mut is_changed = |span| {
+        let (file, lines) = lines(sm, span);
+        changes
+            .file_lines(&file)
+            .map_or(false, |changed| changed.iter().any(|changed| overlaps(changed, &lines)))
+    };
+    is_changed(span) || (span.from_expansion() && is_changed(span.source_callsite()))
+}
+
+declare_lint_pass!(ChangedItems => []);
+
+impl EarlyLintPass for ChangedItems {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        let mut changes = CHANGES.lock().unwrap();
+        let changes = match &mut *changes {
+            Some(changes) => changes,
+            None => return,
+        };
+
+        let mut visitor = ItemVisitor {
+            sm: cx.sess().source_map(),
+            items: FxHashMap::default(),
+        };
+        visit::walk_crate(&mut visitor, krate);
+
+        for (file, items) in visitor.items {
+            let file_lines = match changes.file_lines(&file) {
+                Some(file_lines) => file_lines,
+                None => continue,
+            };
+            let mut widened = Vec::new();
+            for lines in file_lines.iter() {
+                let containing: Vec<_> = items.iter().filter(|item| overlaps(item, lines)).collect();
+                // the innermost items are the ones not containing another one
+                let is_innermost = |item: &RangeInclusive<usize>| {
+                    !containing
+                        .iter()
+                        .any(|other| *other != item && item.contains(other.start()) && item.contains(other.end()))
+                };
+                widened.extend(containing.iter().filter(|item| is_innermost(item)).cloned().cloned());
+            }
+            file_lines.extend(widened);
+        }
+    }
+}
+
+/// Collects the lines of the items of each file.
+struct ItemVisitor<'a> {
+    sm: &'a SourceMap,
+    items: FxHashMap<FileName, Vec<RangeInclusive<usize>>>,
+}
+
+impl ItemVisitor<'_> {
+    fn add(&mut self, span: Span) {
+        if span.from_expansion() {
+            return;
+        }
+        let (file, lines) = lines(self.sm, span);
+        self.items.entry(file).or_default().push(lines);
+    }
+}
+
+impl<'ast> Visitor<'ast> for ItemVisitor<'_> {
+    fn visit_item(&mut self, item: &'ast ast::Item) {
+        self.add(item.span);
+        visit::walk_item(self, item);
+    }
+
+    fn visit_assoc_item(&mut self, item: &'ast ast::AssocItem, ctxt: AssocCtxt) {
+        self.add(item.span);
+        visit::walk_assoc_item(self, item, ctxt);
+    }
+
+    fn visit_foreign_item(&mut self, item: &'ast ast::ForeignItem) {
+        self.add(item.span);
+        visit::walk_foreign_item(self, item);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_diff;
+    use std::path::Path;
+
+    #[test]
+    fn diff() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3 +3 @@ fn foo() {
+-    let x = 1;
++    let x = 2;
+@@ -10,2 +9,0 @@ fn bar() {
+-    bar();
+-    bar();
+@@ -20,0 +19,3 @@ fn baz() {
++    a();
++    b();
++    c();
+diff --git a/src/old.rs b/src/old.rs
+deleted file mode 100644
+--- a/src/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-fn old() {}
+";
+        let changes = parse_diff(Path::new("/repo"), diff);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[Path::new("/repo/src/lib.rs")], [3..=3, 9..=10, 19..=21]);
+    }
+}
@@ -3,9 +3,13 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use rustc_ast::ast::{LitKind, MetaItemKind, NestedMetaItem};
+use rustc_session::lint::Level;
+use rustc_span::lev_distance::lev_distance;
 use rustc_span::source_map;
 use source_map::Span;
+use std::collections::BTreeMap;
 use std::lazy::SyncLazy;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::{env, fmt, fs, io};
@@ -37,13 +41,39 @@ pub enum Error {
     Io(io::Error),
     /// Not valid toml or doesn't fit the expected config format
     Toml(String),
+    /// Like `Toml`, but at a known position of the configuration file.
+    Spanned {
+        /// The error message.
+        msg: String,
+        /// The byte range of the file the error is about.
+        span: Range<usize>,
+        /// A suggestion to fix the error, e.g. the closest valid key.
+        help: Option<String>,
+    },
+    /// A deprecated key, which should be replaced by another one. This is only a warning.
+    Deprecated {
+        /// The deprecated key.
+        key: &'static str,
+        /// The key to use instead.
+        replacement: &'static str,
+        /// The byte range of the deprecated key in the file.
+        span: Range<usize>,
+    },
+    /// An error in a configuration file inherited through `extends` or `inherit`.
+    Inherited(PathBuf, Box<Error>),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Io(err) => err.fmt(f),
-            Self::Toml(err) => err.fmt(f),
+            Self::Toml(err) | Self::Spanned { msg: err, .. } => err.fmt(f),
+            Self::Deprecated { key, replacement, .. } => write!(
+                f,
+                "found deprecated field `{}`. Please use `{}` instead.",
+                key, replacement
+            ),
+            Self::Inherited(file, err) => write!(f, "in inherited file `{}`: {}", file.display(), err),
         }
     }
 }
@@ -54,15 +84,85 @@ impl From<io::Error> for Error {
     }
 }
 
-/// Vec of errors that might be collected during config toml parsing
-static ERRORS: SyncLazy<Mutex<Vec<Error>>> = SyncLazy::new(|| Mutex::new(Vec::new()));
+/// Where the effective value of a configuration key comes from.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct ConfSource {
+    /// The effective value, after merging all the files of the inheritance chain.
+    pub value: toml::Value,
+    /// The files that contributed to the value, outermost first: the file setting a scalar, or all
+    /// the files whose lists or tables were merged.
+    pub files: Vec<PathBuf>,
+}
+
+/// A lint level set in the `[lints]` or `[profile.<name>]` tables of the configuration file.
+#[derive(Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// `allow`
+    Allow,
+    /// `warn`
+    Warn,
+    /// `deny`
+    Deny,
+    /// `forbid`
+    Forbid,
+}
+
+impl From<LintLevel> for Level {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Allow => Self::Allow,
+            LintLevel::Warn => Self::Warn,
+            LintLevel::Deny => Self::Deny,
+            LintLevel::Forbid => Self::Forbid,
+        }
+    }
+}
+
+/// A path in a list like `disallowed-methods`, either as a plain string or as a table giving the
+/// reason it is disallowed. A misspelled field of the table is an error rather than a lost reason.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum DisallowedPath {
+    /// `"std::process::exit"`
+    Simple(String),
+    /// `{ path = "std::process::exit", reason = "..." }`
+    WithReason {
+        /// The path of the item, which can end with `::*` to match everything under it.
+        path: String,
+        /// Why the item is disallowed, shown in the diagnostics.
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// The path of the item, as written in the configuration.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Simple(path) | Self::WithReason { path, .. } => path,
+        }
+    }
+
+    /// Why the item is disallowed, if the configuration says.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+}
+
+/// Vec of errors that might be collected during config toml parsing, along with the key they
+/// were found in
+static ERRORS: SyncLazy<Mutex<Vec<(&'static str, String)>>> = SyncLazy::new(|| Mutex::new(Vec::new()));
 
 macro_rules! define_Conf {
     ($(#[$doc:meta] ($config:ident, $config_str:literal: $Ty:ty, $default:expr),)+) => {
         mod helpers {
             use serde::Deserialize;
             /// Type used to store lint configuration.
-            #[derive(Deserialize)]
+            #[derive(Debug, Deserialize)]
             #[serde(rename_all = "kebab-case", deny_unknown_fields)]
             pub struct Conf {
                 $(
@@ -74,20 +174,35 @@ macro_rules! define_Conf {
                 #[allow(dead_code)]
                 #[serde(default)]
                 third_party: Option<::toml::Value>,
+                /// The file(s) each key set in the configuration was read from.
+                #[serde(skip)]
+                pub sources: ::std::collections::BTreeMap<String, super::ConfSource>,
+            }
+
+            /// The valid keys of a configuration file.
+            pub(super) const KEYS: &[&str] = &[$($config_str,)+ "third_party"];
+
+            /// Checks that `value` fits the type of the key `key`, returning the error message
+            /// otherwise.
+            pub(super) fn check_value(key: &str, value: ::toml::Value) -> Result<(), String> {
+                match key {
+                    $($config_str => <$Ty>::deserialize(value).map(drop).map_err(|e| e.to_string()),)+
+                    _ => Err(format!("unknown field `{}`", key)),
+                }
             }
 
             $(
                 mod $config {
                     use serde::Deserialize;
                     pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<$Ty, D::Error> {
-                        use super::super::{ERRORS, Error};
+                        use super::super::ERRORS;
 
                         Ok(
                             <$Ty>::deserialize(deserializer).unwrap_or_else(|e| {
                                 ERRORS
                                     .lock()
                                     .expect("no threading here")
-                                    .push(Error::Toml(e.to_string()));
+                                    .push(($config_str, e.to_string()));
                                 super::$config()
                             })
                         )
@@ -105,8 +220,9 @@ macro_rules! define_Conf {
 }
 
 pub use self::helpers::Conf;
+use self::helpers::{check_value, KEYS};
 define_Conf! {
-    /// Lint: REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN. The minimum rust version that the project supports
+    /// Lint: REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, INCOMPATIBLE_MSRV, MANUAL_FLATTEN, MAP_CLONE, MAP_COLLECT_RESULT_UNIT, TRANSMUTE_FLOAT_TO_INT. The minimum rust version that the project supports
     (msrv, "msrv": Option<String>, None),
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names, "blacklisted_names": Vec<String>, ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
@@ -169,12 +285,32 @@ define_Conf! {
     (max_fn_params_bools, "max_fn_params_bools": u64, 3),
     /// Lint: WILDCARD_IMPORTS. Whether to allow certain wildcard imports (prelude, super in tests).
     (warn_on_all_wildcard_imports, "warn_on_all_wildcard_imports": bool, false),
-    /// Lint: DISALLOWED_METHOD. The list of disallowed methods, written as fully qualified paths.
-    (disallowed_methods, "disallowed_methods": Vec<String>, Vec::<String>::new()),
+    /// Lint: DISALLOWED_METHOD. The list of disallowed methods, written as paths like `std::process::exit`, or as tables `{ path = "std::process::exit", reason = "..." }`. A path ending with `::*` disallows everything under it.
+    (disallowed_methods, "disallowed_methods": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
+    /// Lint: DISALLOWED_TYPE. The list of disallowed types, written as paths like `std::collections::HashMap`, or as tables `{ path = "std::collections::HashMap", reason = "..." }`.
+    (disallowed_types, "disallowed_types": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
+    /// Lint: DISALLOWED_MACRO. The list of disallowed macros, written as paths like `std::println`, or as tables `{ path = "std::println", reason = "..." }`.
+    (disallowed_macros, "disallowed_macros": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
+    /// Lint: AWAIT_HOLDING_INVALID_TYPE. The list of types which must not be held across an `await` point, written as paths like `tracing::span::Entered`, or as tables `{ path = "tracing::span::Entered", reason = "..." }`.
+    (await_holding_invalid_types, "await_holding_invalid_types": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
     /// Lint: UNREADABLE_LITERAL. Should the fraction of a decimal be linted to include separators.
     (unreadable_literal_lint_fractions, "unreadable_literal_lint_fractions": bool, true),
     /// Lint: _CARGO_COMMON_METADATA. For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     (cargo_ignore_publish, "cargo_ignore_publish": bool, false),
+    /// General: Globs of the files whose diagnostics are dropped, relative to the package directory, like `src/generated/**`.
+    (exclude, "exclude": Vec<String>, Vec::new()),
+    /// General: Drop the diagnostics in generated code: the files included from `OUT_DIR`, and the ones with `@generated` in their first lines.
+    (exclude_generated, "exclude_generated": bool, true),
+    /// General: The lints which are not reported in test code: `#[test]` functions, `#[cfg(test)]` items and doctests, e.g. `["unwrap_used", "expect_used"]`.
+    (allow_in_tests, "allow_in_tests": Vec<String>, Vec::new()),
+    /// General: Inherit the configuration of the nearest `clippy.toml` in a parent directory.
+    (inherit, "inherit": bool, false),
+    /// General: Inherit the configuration of the given file, relative to the file containing this key.
+    (extends, "extends": Option<String>, None),
+    /// General: The levels of Clippy lints and lint groups, e.g. `pedantic = "warn"`, and of the lints and groups of rustc, e.g. `unused_variables = "deny"`.
+    (lints, "lints": std::collections::BTreeMap<String, crate::utils::conf::LintLevel>, std::collections::BTreeMap::new()),
+    /// General: Named sets of lint levels applied on top of `lints`, selected with `cargo clippy --clippy-profile <name>`.
+    (profile, "profile": std::collections::BTreeMap<String, std::collections::BTreeMap<String, crate::utils::conf::LintLevel>>, std::collections::BTreeMap::new()),
 }
 
 impl Default for Conf {
@@ -184,16 +320,115 @@ impl Default for Conf {
     }
 }
 
+/// Lint groups that can be configured in the `[lints]` table, broadest first.
+const LINT_GROUPS: [&str; 9] = [
+    "all",
+    "correctness",
+    "style",
+    "complexity",
+    "perf",
+    "pedantic",
+    "nursery",
+    "cargo",
+    "restriction",
+];
+
+impl Conf {
+    /// The lint levels set by the `[lints]` table and the given profile, in the order they should
+    /// be applied: lint groups before individual lints, and the profile after `[lints]`. The names
+    /// without a tool prefix are Clippy lints, unless `is_rustc_lint` says they are lints or groups
+    /// of rustc, like `unused_variables` or `warnings`.
+    pub fn lint_levels(&self, profile: Option<&str>, is_rustc_lint: impl Fn(&str) -> bool) -> Vec<(String, Level)> {
+        let profile = profile.and_then(|profile| self.profile.get(profile));
+        let mut levels = Vec::new();
+        for table in Some(&self.lints).into_iter().chain(profile) {
+            let mut table: Vec<_> = table.iter().collect();
+            table.sort_by_key(|(name, _)| {
+                let name = name.trim_start_matches("clippy::");
+                LINT_GROUPS
+                    .iter()
+                    .position(|group| *group == name)
+                    .unwrap_or(LINT_GROUPS.len())
+            });
+            levels.extend(table.into_iter().map(|(name, level)| {
+                let name = if name.contains("::") || is_rustc_lint(name) {
+                    name.clone()
+                } else {
+                    format!("clippy::{}", name)
+                };
+                (name, Level::from(*level))
+            }));
+        }
+        levels
+    }
+}
+
+/// The keys that can be set for an item and everything in it with a `#[clippy::conf(..)]`
+/// attribute: the numeric keys and the lists. The lists of paths are resolved again for the items
+/// setting them, and the globs of `exclude` and the lints of `allow_in_tests` apply to the code
+/// of the items.
+pub const OVERRIDABLE_KEYS: [&str; 25] = [
+    "allow_in_tests",
+    "array_size_threshold",
+    "await_holding_invalid_types",
+    "blacklisted_names",
+    "cognitive_complexity_threshold",
+    "disallowed_macros",
+    "disallowed_methods",
+    "disallowed_types",
+    "doc_valid_idents",
+    "enum_variant_name_threshold",
+    "enum_variant_size_threshold",
+    "exclude",
+    "literal_representation_threshold",
+    "max_fn_params_bools",
+    "max_struct_bools",
+    "max_trait_bounds",
+    "pass_by_value_size_limit",
+    "single_char_binding_names_threshold",
+    "too_large_for_stack",
+    "too_many_arguments_threshold",
+    "too_many_lines_threshold",
+    "trivial_copy_size_limit",
+    "type_complexity_threshold",
+    "vec_box_size_threshold",
+    "verbose_bit_mask_threshold",
+];
+
+/// Checks the value a `#[clippy::conf(..)]` attribute sets for `key`. The error is a message
+/// along with an optional help.
+pub fn check_override(key: &str, value: toml::Value) -> Result<(), (String, Option<String>)> {
+    if !OVERRIDABLE_KEYS.contains(&key) {
+        return Err(if KEYS.contains(&key) {
+            (
+                format!("`{}` can't be set by an attribute", key),
+                Some("set it in `clippy.toml` instead".to_string()),
+            )
+        } else {
+            (
+                format!("unknown configuration key `{}`", key),
+                closest_key(key, OVERRIDABLE_KEYS.iter().map(ToString::to_string)),
+            )
+        });
+    }
+    check_value(key, value).map_err(|msg| (format!("invalid value for `{}`: {}", key, msg), None))
+}
+
 /// Search for the configuration file.
 pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
-
     // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
     // If neither of those exist, use ".".
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
+    let current = env::var_os("CLIPPY_CONF_DIR")
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
         .map_or_else(|| PathBuf::from("."), PathBuf::from);
+    find_conf_file(current)
+}
+
+/// Search for the configuration file in `current` and its parent directories.
+fn find_conf_file(mut current: PathBuf) -> io::Result<Option<PathBuf>> {
+    /// Possible filename to search for.
+    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
     loop {
         for config_file_name in &CONFIG_FILE_NAMES {
             let config_file = current.join(config_file_name);
@@ -215,6 +450,32 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
     }
 }
 
+/// Reads the MSRVs set in the manifest of the package being checked, from
+/// `[package.metadata.clippy] msrv` and from `rust-version`, in that order of precedence. Each one
+/// is returned along with the name of the key it was read from.
+pub fn package_msrvs() -> Vec<(&'static str, String)> {
+    let manifest = match env::var_os("CARGO_MANIFEST_DIR")
+        .and_then(|dir| fs::read_to_string(Path::new(&dir).join("Cargo.toml")).ok())
+        .and_then(|content| content.parse::<toml::Value>().ok())
+    {
+        Some(manifest) => manifest,
+        None => return Vec::new(),
+    };
+    let package = manifest.get("package");
+    let metadata = package
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("clippy"))
+        .and_then(|clippy| clippy.get("msrv"));
+    let rust_version = package.and_then(|package| package.get("rust-version"));
+    vec![
+        ("[package.metadata.clippy] msrv", metadata),
+        ("rust-version", rust_version),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| Some((key, value?.as_str()?.to_string())))
+    .collect()
+}
+
 /// Produces a `Conf` filled with the default values and forwards the errors
 ///
 /// Used internally for convenience
@@ -222,35 +483,229 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (Conf::default(), errors)
 }
 
-/// Read the `toml` configuration file.
+/// Configuration keys that are deprecated, along with the keys replacing them.
+const DEPRECATED_KEYS: [(&str, &str); 1] = [("cyclomatic-complexity-threshold", "cognitive-complexity-threshold")];
+
+/// Converts an error of the `toml` parser to an `Error` pointing at the position it was found at.
+fn syntax_error(content: &str, error: &toml::de::Error) -> Error {
+    let msg = error.to_string();
+    match error.line_col() {
+        Some((line, col)) => {
+            let start = content.split('\n').take(line).map(|line| line.len() + 1).sum::<usize>() + col;
+            let start = start.min(content.len());
+            // The position is already part of the span, don't repeat it in the message.
+            let msg = msg.rfind(" at line ").map_or(msg.as_str(), |pos| &msg[..pos]);
+            Error::Spanned {
+                msg: msg.to_string(),
+                span: start..start,
+                help: None,
+            }
+        },
+        None => Error::Toml(msg),
+    }
+}
+
+/// Suggests the closest key of `known` to the unknown key `key`, if there is one.
+pub fn closest_key(key: &str, known: impl Iterator<Item = String>) -> Option<String> {
+    let max_dist = std::cmp::max(key.len(), 3) / 3;
+    known
+        .map(|known| (lev_distance(key, &known), known))
+        .filter(|(dist, _)| *dist <= max_dist)
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, known)| format!("did you mean `{}`?", known))
+}
+
+/// Builds the error for an unknown key, suggesting the closest valid key if there is one.
+fn unknown_key(key: &str, span: Range<usize>) -> Error {
+    let help = closest_key(key, KEYS.iter().map(|known| known.replace('_', "-")));
+    Error::Spanned {
+        msg: format!("unknown field `{}`", key),
+        span,
+        help,
+    }
+}
+
+/// Parse the content of a single configuration file.
 ///
-/// In case of error, the function tries to continue as much as possible.
-pub fn read(path: &Path) -> (Conf, Vec<Error>) {
+/// Returns `Err` if the file can't be used at all. Otherwise the errors in the returned `Vec` only
+/// affect individual fields, which are set to their default value, and the returned table only
+/// contains the known keys.
+fn parse(content: &str) -> Result<(Conf, toml::value::Table, Vec<Error>), Vec<Error>> {
+    let spanned: BTreeMap<toml::Spanned<String>, toml::Spanned<toml::Value>> = match toml::from_str(content) {
+        Ok(spanned) => spanned,
+        Err(e) => return Err(vec![syntax_error(content, &e)]),
+    };
+
+    let mut spanned: Vec<_> = spanned.into_iter().collect();
+    spanned.sort_by_key(|(key, _)| key.start());
+
+    let mut errors = Vec::new();
+    let mut table = toml::value::Table::new();
+    let mut value_spans = BTreeMap::new();
+    for (key, value) in spanned {
+        let key_span = key.start()..key.end();
+        let key = key.into_inner();
+        if !KEYS.iter().any(|known| known.replace('_', "-") == key) {
+            errors.push(unknown_key(&key, key_span));
+            continue;
+        }
+        if let Some(&(key, replacement)) = DEPRECATED_KEYS.iter().find(|(deprecated, _)| *deprecated == key) {
+            errors.push(Error::Deprecated {
+                key,
+                replacement,
+                span: key_span,
+            });
+        }
+        value_spans.insert(key.replace('-', "_"), value.start()..value.end());
+        table.insert(key, value.into_inner());
+    }
+
+    assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
+    let conf = toml::Value::Table(table.clone()).try_into::<Conf>();
+    let field_errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    errors.extend(field_errors.into_iter().map(|(key, msg)| match value_spans.get(key) {
+        Some(span) => Error::Spanned {
+            msg,
+            span: span.clone(),
+            help: None,
+        },
+        None => Error::Toml(msg),
+    }));
+
+    match conf {
+        Ok(conf) => Ok((conf, table, errors)),
+        Err(e) => {
+            errors.push(Error::Toml(e.to_string()));
+            Err(errors)
+        },
+    }
+}
+
+/// Read a configuration file and the files it inherits from.
+///
+/// The tables of the files that could be read are pushed to `chain`, outermost file first. A file
+/// that can't be parsed ends the chain: neither it nor the files it would inherit from are used.
+fn read_chain(path: &Path, chain: &mut Vec<(PathBuf, toml::value::Table)>, visited: &mut Vec<PathBuf>) -> Vec<Error> {
     let content = match fs::read_to_string(path) {
         Ok(content) => content,
-        Err(err) => return default(vec![err.into()]),
+        Err(err) => return vec![err.into()],
     };
 
-    assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
-    match toml::from_str(&content) {
-        Ok(toml) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    let (conf, table, mut errors) = match parse(&content) {
+        Ok(parsed) => parsed,
+        Err(errors) => return errors,
+    };
+
+    let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if visited.contains(&canonical) {
+        errors.push(Error::Toml(format!(
+            "`{}` is inherited more than once, the configuration files form a cycle",
+            path.display()
+        )));
+        return errors;
+    }
+
+    let dir = canonical.parent().unwrap_or_else(|| Path::new(""));
+    let parent = match (conf.extends, conf.inherit) {
+        (Some(_), true) => {
+            errors.push(Error::Toml(
+                "`extends` and `inherit` can't be used in the same file".to_string(),
+            ));
+            None
+        },
+        (Some(file), false) => Some(dir.join(file)),
+        (None, true) => match dir.parent().map(|dir| find_conf_file(dir.to_path_buf())) {
+            Some(Ok(Some(file))) => Some(file),
+            Some(Err(err)) => {
+                errors.push(err.into());
+                None
+            },
+            Some(Ok(None)) | None => {
+                errors.push(Error::Toml(
+                    "`inherit` is set but no configuration file was found in the parent directories".to_string(),
+                ));
+                None
+            },
+        },
+        (None, false) => None,
+    };
+
+    visited.push(canonical);
+    if let Some(parent) = parent {
+        errors.extend(read_chain(&parent, chain, visited).into_iter().map(|err| match err {
+            Error::Inherited(..) => err,
+            _ => Error::Inherited(parent.clone(), Box::new(err)),
+        }));
+    }
+    chain.push((path.to_path_buf(), table));
 
-            let toml_ref: &Conf = &toml;
+    errors
+}
+
+/// Merge `table` into `merged`, where `table` comes from a file inheriting the configuration
+/// `merged` comes from.
+///
+/// Scalars from the inheriting file replace the inherited ones, lists are appended to the inherited
+/// lists (skipping the elements already present) and tables are merged key by key.
+fn merge(merged: &mut toml::value::Table, table: toml::value::Table) {
+    for (key, value) in table {
+        match (merged.get_mut(&key), value) {
+            (Some(toml::Value::Array(merged)), toml::Value::Array(values)) => {
+                for value in values {
+                    if !merged.contains(&value) {
+                        merged.push(value);
+                    }
+                }
+            },
+            (Some(toml::Value::Table(merged)), toml::Value::Table(table)) => merge(merged, table),
+            (_, value) => {
+                merged.insert(key, value);
+            },
+        }
+    }
+}
 
-            let cyc_field: Option<u64> = toml_ref.cyclomatic_complexity_threshold;
+/// Read the `toml` configuration file, along with the files it inherits from through the `extends`
+/// or `inherit` keys.
+///
+/// In case of error, the function tries to continue as much as possible.
+pub fn read(path: &Path) -> (Conf, Vec<Error>) {
+    let mut chain = Vec::new();
+    let mut errors = read_chain(path, &mut chain, &mut Vec::new());
 
-            if cyc_field.is_some() {
-                let cyc_err = "found deprecated field `cyclomatic-complexity-threshold`. Please use `cognitive-complexity-threshold` instead.".to_string();
-                errors.push(Error::Toml(cyc_err));
+    let mut merged = toml::value::Table::new();
+    let mut files = BTreeMap::<_, Vec<PathBuf>>::new();
+    for (file, mut table) in chain {
+        table.remove("inherit");
+        table.remove("extends");
+        for (key, value) in &table {
+            let key_files = files.entry(key.clone()).or_default();
+            // a scalar replaces the inherited value, lists and tables are merged with it
+            if !matches!(value, toml::Value::Array(_) | toml::Value::Table(_)) {
+                key_files.clear();
             }
+            key_files.push(file.clone());
+        }
+        merge(&mut merged, table);
+    }
+
+    let conf = toml::Value::Table(merged.clone()).try_into::<Conf>();
+    // The errors of the individual files have already been collected by `read_chain`.
+    ERRORS.lock().expect("no threading -> mutex always safe").clear();
 
-            (toml, errors)
+    match conf {
+        Ok(mut conf) => {
+            conf.sources = files
+                .into_iter()
+                .filter_map(|(key, files)| {
+                    let value = merged.remove(&key)?;
+                    Some((key, ConfSource { value, files }))
+                })
+                .collect();
+            (conf, errors)
         },
         Err(e) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
             errors.push(Error::Toml(e.to_string()));
-
             default(errors)
         },
     }
@@ -1,11 +1,23 @@
 //! Clippy wrappers around rustc's diagnostic functions.
 
+use crate::utils::{allow_in_tests, changed_lines, excluded_files};
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir::HirId;
 use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_session::Session;
 use rustc_span::source_map::{MultiSpan, Span};
 use std::env;
 
+/// Whether `lint` is reported at `sp`. Lints in excluded files aren't, nor lints allowed in tests
+/// in test code, nor lints outside of the changed lines with `--changed-since`.
+fn is_reported(sess: &Session, lint: &'static Lint, sp: &MultiSpan) -> bool {
+    sp.primary_span().map_or(true, |sp| {
+        excluded_files::is_reported(sess.source_map(), sp)
+            && allow_in_tests::is_reported(lint, sp)
+            && changed_lines::is_reported(sess.source_map(), sp)
+    })
+}
+
 fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         diag.help(&format!(
@@ -37,6 +49,10 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    let sp = sp.into();
+    if !is_reported(cx.sess(), lint, &sp) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
@@ -72,6 +88,9 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    if !is_reported(cx.sess(), lint, &span.into()) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(help_span) = help_span {
@@ -115,6 +134,10 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    let span = span.into();
+    if !is_reported(cx.sess(), lint, &span) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(note_span) = note_span {
@@ -135,6 +158,9 @@ pub fn span_lint_and_then<'a, T: LintContext, F>(cx: &'a T, lint: &'static Lint,
 where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
+    if !is_reported(cx.sess(), lint, &sp.into()) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
@@ -144,6 +170,9 @@ where
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    if !is_reported(cx.tcx.sess, lint, &sp.into()) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
@@ -159,6 +188,9 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
+    if !is_reported(cx.tcx.sess, lint, &sp.into()) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
@@ -0,0 +1,195 @@
+//! The lists of paths of the `disallowed-*` configurations, resolved to the items they name.
+//!
+//! A list is resolved once for the crate, and again for each item setting it with a
+//! `#[clippy::conf(..)]` attribute, when the lint pass enters the item.
+
+use crate::utils::conf::DisallowedPath;
+use crate::utils::{path_to_def_id, span_lint_and_then, ConfStack};
+use rustc_ast::ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::DiagnosticBuilder;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_middle::ty::{DefIdTree, Visibility};
+use rustc_span::{Span, Symbol};
+
+/// The list of paths of a configuration key, resolved for the innermost scope setting it.
+#[derive(Clone, Debug)]
+pub struct DisallowedPaths {
+    conf: ConfStack<Vec<DisallowedPath>>,
+    /// Whether a path ending with `::*` matches everything under the item it names.
+    globs: bool,
+    /// The resolution of each list in scope, the innermost one last.
+    resolved: Vec<Resolved>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Resolved {
+    /// The configured items, mapped to their index in the list.
+    def_ids: FxHashMap<DefId, usize>,
+    /// The modules, types and traits configured with `::*`, and the items they re-export, mapped
+    /// to their index in the list.
+    globs: FxHashMap<DefId, usize>,
+    /// The configured paths which don't resolve, like the ones of local items, compared with the
+    /// definition paths. The `bool` is whether the path ends with `::*`.
+    def_paths: Vec<(Vec<Symbol>, bool, usize)>,
+}
+
+impl DisallowedPaths {
+    /// The list `conf` of the configuration key `key`, with the paths ending with `::*` naming
+    /// items rather than everything under them.
+    pub fn new(key: &'static str, conf: &[DisallowedPath]) -> Self {
+        Self {
+            conf: ConfStack::new(key, conf.to_vec()),
+            globs: false,
+            resolved: Vec::new(),
+        }
+    }
+
+    /// Makes the paths ending with `::*` match all the items under a module, a type or a trait.
+    #[must_use]
+    pub fn with_globs(self) -> Self {
+        Self { globs: true, ..self }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conf.current().is_empty()
+    }
+
+    /// The configured path disallowing `def_id`, if there is one.
+    pub fn get(&self, cx: &LateContext<'_>, def_id: DefId) -> Option<&DisallowedPath> {
+        let index = self.resolved.last()?.index(cx, def_id)?;
+        self.conf.current().get(index)
+    }
+
+    /// Resolves the list set by the `clippy::conf` attributes among `attrs`, if they set one.
+    pub fn push_attrs(&mut self, cx: &LateContext<'_>, attrs: &[ast::Attribute]) {
+        // the attributes of the crate are entered before `check_crate`, so the list of the
+        // configuration file is resolved with them
+        if self.conf.push_attrs(cx.sess(), attrs) || self.resolved.is_empty() {
+            let resolved = Resolved::new(cx, self.conf.current(), self.globs);
+            self.resolved.push(resolved);
+        }
+    }
+
+    pub fn pop_attrs(&mut self, cx: &LateContext<'_>, attrs: &[ast::Attribute]) {
+        if self.conf.pop_attrs(cx.sess(), attrs) {
+            self.resolved.pop();
+        }
+    }
+}
+
+impl DisallowedPath {
+    /// Emits `lint` at `span` for the use of the item `def_id` disallowed by this path, with the
+    /// kind of the item, like `"type"`, in the message.
+    pub fn lint(&self, cx: &LateContext<'_>, lint: &'static Lint, span: Span, what: &str, def_id: DefId) {
+        self.lint_and_then(
+            cx,
+            lint,
+            span,
+            def_id,
+            |path| format!("use of a disallowed {} `{}`", what, path),
+            |_| {},
+        );
+    }
+
+    /// Emits `lint` at `span` for the use of the item `def_id` disallowed by this path, with the
+    /// message `msg` makes from the path of the item. The reason of the path is noted after the
+    /// notes added by `f`.
+    pub fn lint_and_then(
+        &self,
+        cx: &LateContext<'_>,
+        lint: &'static Lint,
+        span: Span,
+        def_id: DefId,
+        msg: impl FnOnce(&str) -> String,
+        f: impl for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
+    ) {
+        let path = cx
+            .get_def_path(def_id)
+            .into_iter()
+            .map(Symbol::to_ident_string)
+            .collect::<Vec<_>>()
+            .join("::");
+        span_lint_and_then(cx, lint, span, &msg(&path), |diag| {
+            f(diag);
+            if let Some(reason) = self.reason() {
+                diag.note(reason);
+            }
+        });
+    }
+}
+
+impl Resolved {
+    fn new(cx: &LateContext<'_>, conf: &[DisallowedPath], globs: bool) -> Self {
+        let mut resolved = Self::default();
+        for (index, conf) in conf.iter().enumerate() {
+            let (path, glob) = match conf.path().strip_suffix("::*") {
+                Some(path) if globs => (path, true),
+                _ => (conf.path(), false),
+            };
+            let segments: Vec<_> = path.split("::").collect();
+            match path_to_def_id(cx, &segments) {
+                Some(def_id) if glob => {
+                    resolved.globs.insert(def_id, index);
+                    // the items a module re-exports, like the types of `core` in `std`
+                    if !def_id.is_local() && cx.tcx.def_kind(def_id) == DefKind::Mod {
+                        let children = cx.tcx.item_children(def_id).iter();
+                        for child in children.filter(|child| child.vis == Visibility::Public) {
+                            if let Some(child_id) = child.res.opt_def_id() {
+                                resolved.globs.entry(child_id).or_insert(index);
+                            }
+                        }
+                    }
+                },
+                Some(def_id) => {
+                    resolved.def_ids.insert(def_id, index);
+                },
+                None => resolved
+                    .def_paths
+                    .push((segments.into_iter().map(Symbol::intern).collect(), glob, index)),
+            }
+        }
+        resolved
+    }
+
+    fn index(&self, cx: &LateContext<'_>, def_id: DefId) -> Option<usize> {
+        if let Some(&index) = self.def_ids.get(&def_id) {
+            return Some(index);
+        }
+        if !self.globs.is_empty() {
+            if let Some(index) = owners(cx, def_id).iter().find_map(|owner| self.globs.get(owner)) {
+                return Some(*index);
+            }
+        }
+        if self.def_paths.is_empty() {
+            return None;
+        }
+        let def_path = cx.get_def_path(def_id);
+        self.def_paths.iter().find_map(|(path, glob, index)| {
+            let matches = if *glob {
+                def_path.len() > path.len() && def_path.starts_with(path)
+            } else {
+                def_path == *path
+            };
+            matches.then(|| *index)
+        })
+    }
+}
+
+/// The items `def_id` is defined under: its parents, and the types of the impls among them.
+fn owners(cx: &LateContext<'_>, def_id: DefId) -> Vec<DefId> {
+    let mut owners = Vec::new();
+    let mut parent = cx.tcx.parent(def_id);
+    while let Some(def_id) = parent {
+        owners.push(def_id);
+        if cx.tcx.def_kind(def_id) == DefKind::Impl {
+            if let Some(adt) = cx.tcx.type_of(def_id).ty_adt_def() {
+                owners.push(adt.did);
+            }
+        }
+        parent = cx.tcx.parent(def_id);
+    }
+    owners
+}
@@ -0,0 +1,210 @@
+//! Skips the lint passes whose lints can't fire anywhere in the crate.
+//!
+//! A lint can only fire if its default level, the command line, the configuration file or a lint
+//! attribute somewhere in the crate sets it above `allow`. The early passes are constructed once
+//! the crate is expanded, after the early lint checks, so the lints which can fire are read before
+//! the expansion by [`EnabledLintsCollector`], from the lint attributes of each source file,
+//! including the ones in `cfg_attr` and the `clippy::` lints named in the macros of the crate. For
+//! the late passes, they are read again from the expanded crate, which also has the lint attributes
+//! written by the macros of other crates.
+//!
+//! A pass none of whose lints can fire is replaced with a [`Disabled`] pass, which does nothing.
+//! The lints of each pass are listed in `PASS_LINTS` in `lib.rs`, and the passes which are not
+//! listed always run.
+
+use rustc_ast::ast;
+use rustc_ast::token::{Token, TokenKind};
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
+use rustc_ast::visit::{self, Visitor};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateLintPass, Level, LintId, LintPass, LintStore};
+use rustc_session::{declare_lint_pass, Session};
+use rustc_span::sym;
+use std::lazy::SyncLazy;
+use std::sync::Mutex;
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + sync::Sync + 'static>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync + 'static>;
+
+/// The lints of each pass, by the name of the pass.
+pub type PassLints = &'static [(&'static str, &'static [&'static str])];
+
+/// The names of the Clippy lints which can fire in the crate, without the `clippy::` prefix, or
+/// `None` if they were not read, in which case all the passes run.
+static ENABLED: SyncLazy<Mutex<Option<FxHashSet<String>>>> = SyncLazy::new(Mutex::default);
+
+/// The lints set above `allow` by their default level, the command line or the configuration file.
+fn enabled_by_options(sess: &Session, store: &LintStore) -> FxHashSet<LintId> {
+    let mut levels: Vec<(LintId, Level)> = store
+        .get_lints()
+        .iter()
+        .map(|&lint| (LintId::of(lint), lint.default_level(sess.edition())))
+        .collect();
+    for (name, level) in &sess.opts.lint_opts {
+        if let Ok(ids) = store.find_lints(&name.replace('-', "_")) {
+            for (id, lint_level) in &mut levels {
+                if ids.contains(id) {
+                    *lint_level = *level;
+                }
+            }
+        }
+    }
+    levels
+        .into_iter()
+        .filter(|(_, level)| *level != Level::Allow)
+        .map(|(id, _)| id)
+        .collect()
+}
+
+/// Adds the lints which can fire in `krate`, which is a source file before the expansion or the
+/// expanded crate, to the lints which can fire.
+pub fn read(sess: &Session, store: &LintStore, krate: &ast::Crate) {
+    let mut visitor = AttrVisitor { names: Vec::new() };
+    visit::walk_crate(&mut visitor, krate);
+
+    let mut enabled = ENABLED.lock().unwrap();
+    let enabled = enabled.get_or_insert_with(|| {
+        enabled_by_options(sess, store)
+            .iter()
+            .filter_map(|id| id.lint.name_lower().strip_prefix("clippy::").map(ToString::to_string))
+            .collect()
+    });
+    for name in visitor.names {
+        for id in store.find_lints(&name).unwrap_or_default() {
+            if let Some(name) = id.lint.name_lower().strip_prefix("clippy::") {
+                enabled.insert(name.to_string());
+            }
+        }
+    }
+}
+
+/// Collects the names of the lints set above `allow` by the lint attributes, and of the `clippy::`
+/// lints named in macros, which may write lint attributes once expanded.
+struct AttrVisitor {
+    names: Vec<String>,
+}
+
+impl AttrVisitor {
+    fn visit_lint_attribute(&mut self, name: rustc_span::Symbol, items: &[ast::NestedMetaItem]) {
+        if name == sym::cfg_attr {
+            // the attributes are kept whatever the configuration predicate is
+            for item in items.iter().skip(1).filter_map(ast::NestedMetaItem::meta_item) {
+                self.visit_lint_attribute(item.name_or_empty(), item.meta_item_list().unwrap_or_default());
+            }
+        } else if matches!(Level::from_symbol(name), Some(level) if level != Level::Allow) {
+            for item in items.iter().filter_map(ast::NestedMetaItem::meta_item) {
+                let segments: Vec<_> = item.path.segments.iter().map(|s| s.ident.to_string()).collect();
+                self.names.push(segments.join("::"));
+            }
+        }
+    }
+
+    fn visit_tokens(&mut self, tokens: &TokenStream) {
+        let trees: Vec<_> = tokens.trees().collect();
+        for (i, tree) in trees.iter().enumerate() {
+            match tree {
+                TokenTree::Token(Token {
+                    kind: TokenKind::Ident(name, false),
+                    ..
+                }) if name.as_str() == "clippy" => {
+                    if let Some(
+                        [TokenTree::Token(Token {
+                            kind: TokenKind::ModSep,
+                            ..
+                        }), TokenTree::Token(Token {
+                            kind: TokenKind::Ident(lint, _),
+                            ..
+                        })],
+                    ) = trees.get(i + 1..i + 3)
+                    {
+                        self.names.push(format!("clippy::{}", lint));
+                    }
+                },
+                TokenTree::Delimited(_, _, tokens) => self.visit_tokens(tokens),
+                TokenTree::Token(_) => {},
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for AttrVisitor {
+    fn visit_attribute(&mut self, attr: &'ast ast::Attribute) {
+        self.visit_lint_attribute(attr.name_or_empty(), &attr.meta_item_list().unwrap_or_default());
+    }
+
+    fn visit_mac_call(&mut self, mac: &'ast ast::MacCall) {
+        self.visit_tokens(&mac.args.inner_tokens());
+        visit::walk_mac(self, mac);
+    }
+
+    fn visit_mac_def(&mut self, mac: &'ast ast::MacroDef, _: ast::NodeId) {
+        self.visit_tokens(&mac.body.inner_tokens());
+    }
+}
+
+declare_lint_pass!(
+    /// Reads the lints which can fire in each source file before the expansion, see the module
+    /// documentation.
+    EnabledLintsCollector => []
+);
+
+impl EarlyLintPass for EnabledLintsCollector {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        read(cx.sess, cx.lint_store, krate);
+    }
+}
+
+/// Whether the pass `name` may run, which is the case unless none of its lints can fire.
+fn is_enabled(pass_lints: PassLints, name: &str) -> bool {
+    let enabled = ENABLED.lock().unwrap();
+    let enabled = match &*enabled {
+        Some(enabled) => enabled,
+        None => return true,
+    };
+    pass_lints
+        .iter()
+        .find(|(pass, _)| *pass == name)
+        .map_or(true, |(_, lints)| lints.iter().any(|lint| enabled.contains(*lint)))
+}
+
+/// Replaces the early and late passes registered after the first `early` and `late` ones with a
+/// [`Disabled`] pass when none of their lints, as listed in `pass_lints`, can fire.
+pub fn skip_disabled_passes(store: &mut LintStore, early: usize, late: usize, pass_lints: PassLints) {
+    let passes: Vec<_> = store.early_passes.drain(early..).collect();
+    for constructor in passes {
+        store.early_passes.push(Box::new(move || -> EarlyLintPassObject {
+            let pass = constructor();
+            if is_enabled(pass_lints, pass.name()) {
+                pass
+            } else {
+                box Disabled(pass.name())
+            }
+        }));
+    }
+    let passes: Vec<_> = store.late_passes.drain(late..).collect();
+    for constructor in passes {
+        store.late_passes.push(Box::new(move || -> LateLintPassObject {
+            let pass = constructor();
+            if is_enabled(pass_lints, pass.name()) {
+                pass
+            } else {
+                box Disabled(pass.name())
+            }
+        }));
+    }
+}
+
+/// A lint pass which does nothing, in place of the pass of the given name.
+struct Disabled(&'static str);
+
+// `declare_lint_pass!` can't take the name of the replaced pass.
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for Disabled {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl EarlyLintPass for Disabled {}
+impl LateLintPass<'_> for Disabled {}
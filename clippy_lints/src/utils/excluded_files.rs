@@ -0,0 +1,213 @@
+//! Drops the Clippy diagnostics in the files excluded by the `exclude` configuration, and in
+//! generated code unless `exclude-generated = false`.
+//!
+//! The globs are relative to the directory of the package being checked. A glob matching a
+//! directory excludes the files under it. Generated code is the code included from `OUT_DIR`,
+//! and the files with `@generated` in their first lines, like the ones written by protoc or
+//! bindgen.
+//!
+//! An item with a `#[clippy::conf(exclude(..))]` attribute sets the globs of the diagnostics under
+//! it, like the ones in the file of a module.
+
+use crate::utils::attrs::conf_attr_scopes;
+use crate::utils::conf::Conf;
+use rustc_ast::ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::source_map::SourceMap;
+use rustc_span::{BytePos, FileName, SourceFile, Span};
+use std::env;
+use std::lazy::SyncLazy;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// The number of lines searched for the `@generated` marker.
+const GENERATED_MARKER_LINES: usize = 5;
+
+struct Exclusions {
+    /// The globs, as the components of absolute paths.
+    globs: Vec<Vec<String>>,
+    /// The globs of the items with a `#[clippy::conf(exclude(..))]` attribute, the inner items
+    /// last.
+    scopes: Vec<(Range<BytePos>, Vec<Vec<String>>)>,
+    /// The directory of the generated files of the build script, if generated code is excluded.
+    out_dir: Option<PathBuf>,
+    generated: bool,
+    /// Whether each file already looked up is excluded, with the globs of the configuration file or
+    /// of a scope.
+    files: FxHashMap<(FileName, Option<usize>), bool>,
+}
+
+/// The exclusions the diagnostics are filtered with, if any.
+static EXCLUSIONS: SyncLazy<Mutex<Option<Exclusions>>> = SyncLazy::new(|| Mutex::new(None));
+
+/// Reads the exclusions from `conf`.
+pub fn read(conf: &Conf) {
+    let exclusions = if conf.exclude.is_empty() && !conf.exclude_generated {
+        None
+    } else {
+        let out_dir = env::var_os("OUT_DIR")
+            .filter(|_| conf.exclude_generated)
+            .map(PathBuf::from)
+            .map(|out_dir| out_dir.canonicalize().unwrap_or(out_dir));
+        Some(Exclusions {
+            globs: globs(&conf.exclude),
+            scopes: Vec::new(),
+            out_dir,
+            generated: conf.exclude_generated,
+            files: FxHashMap::default(),
+        })
+    };
+    *EXCLUSIONS.lock().unwrap() = exclusions;
+}
+
+/// The components of `globs`, relative to the directory of the package.
+fn globs(globs: &[String]) -> Vec<Vec<String>> {
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::current_dir().ok())
+        .unwrap_or_default();
+    let root = root.canonicalize().unwrap_or(root);
+    globs
+        .iter()
+        .filter(|glob| !glob.is_empty())
+        .map(|glob| components(&root.join(glob)))
+        .collect()
+}
+
+fn components(path: &Path) -> Vec<String> {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Whether the path with the components `path`, or one of its parent directories, matches the
+/// glob with the components `glob`.
+fn matches(glob: &[String], path: &[String]) -> bool {
+    match glob.split_first() {
+        None => true,
+        Some((first, rest)) if first == "**" => (0..=path.len()).any(|skipped| matches(rest, &path[skipped..])),
+        Some((first, rest)) => path.split_first().map_or(false, |(name, path_rest)| {
+            let first: Vec<_> = first.chars().collect();
+            let name: Vec<_> = name.chars().collect();
+            matches_name(&first, &name) && matches(rest, path_rest)
+        }),
+    }
+}
+
+/// Whether the file name `name` matches the glob `glob`, with `*` and `?` wildcards.
+fn matches_name(glob: &[char], name: &[char]) -> bool {
+    match glob.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skipped| matches_name(rest, &name[skipped..])),
+        Some((first, rest)) => name.split_first().map_or(false, |(c, name_rest)| {
+            (*first == '?' || first == c) && matches_name(rest, name_rest)
+        }),
+    }
+}
+
+fn is_generated(file: &SourceFile) -> bool {
+    file.src.as_ref().map_or(false, |src| {
+        src.lines()
+            .take(GENERATED_MARKER_LINES)
+            .any(|line| line.contains("@generated"))
+    })
+}
+
+impl Exclusions {
+    /// The innermost scope with its own globs containing `span`, or its macro call site, like the
+    /// code included in a module.
+    fn scope(&self, span: Span) -> Option<usize> {
+        let contains = |range: &Range<BytePos>, span: Span| range.start <= span.lo() && span.hi() <= range.end;
+        self.scopes
+            .iter()
+            .rposition(|(range, _)| contains(range, span) || contains(range, span.source_callsite()))
+    }
+
+    fn is_excluded(&mut self, file: &SourceFile, scope: Option<usize>) -> bool {
+        if let Some(&excluded) = self.files.get(&(file.name.clone(), scope)) {
+            return excluded;
+        }
+        let globs = scope.map_or(&self.globs, |scope| &self.scopes[scope].1);
+        let path = match &file.name {
+            FileName::Real(name) => env::current_dir().ok().map(|dir| {
+                let path = dir.join(name.local_path());
+                path.canonicalize().unwrap_or(path)
+            }),
+            _ => None,
+        };
+        let excluded = path.map_or(false, |path| {
+            let path_components = components(&path);
+            globs.iter().any(|glob| matches(glob, &path_components))
+                || self.out_dir.as_ref().map_or(false, |out_dir| path.starts_with(out_dir))
+        }) || (self.generated && is_generated(file));
+        self.files.insert((file.name.clone(), scope), excluded);
+        excluded
+    }
+}
+
+/// Whether a diagnostic at `span` is reported, which is the case unless `span`, or its macro
+/// call site, is in an excluded file.
+pub fn is_reported(sm: &SourceMap, span: Span) -> bool {
+    let mut exclusions = EXCLUSIONS.lock().unwrap();
+    let exclusions = match &mut *exclusions {
+        Some(exclusions) => exclusions,
+        None => return true,
+    };
+    let mut is_excluded = |span: Span| {
+        let scope = exclusions.scope(span);
+        exclusions.is_excluded(&sm.lookup_char_pos(span.lo()).file, scope)
+    };
+    !(is_excluded(span) || (span.from_expansion() && is_excluded(span.source_callsite())))
+}
+
+declare_lint_pass!(ExclusionCollector => []);
+
+impl EarlyLintPass for ExclusionCollector {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+        let scopes = conf_attr_scopes::<Vec<String>>(cx.sess, krate, "exclude");
+        if scopes.is_empty() {
+            return;
+        }
+        let mut exclusions = EXCLUSIONS.lock().unwrap();
+        let exclusions = exclusions.get_or_insert_with(|| Exclusions {
+            globs: Vec::new(),
+            scopes: Vec::new(),
+            out_dir: None,
+            generated: false,
+            files: FxHashMap::default(),
+        });
+        exclusions.scopes = scopes
+            .into_iter()
+            .map(|(range, scope)| (range, globs(&scope)))
+            .collect();
+        exclusions.files.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{components, matches};
+    use std::path::Path;
+
+    fn glob_matches(glob: &str, path: &str) -> bool {
+        matches(&components(Path::new(glob)), &components(Path::new(path)))
+    }
+
+    #[test]
+    fn globs() {
+        assert!(glob_matches("/repo/src/generated/**", "/repo/src/generated/proto/a.rs"));
+        assert!(glob_matches("/repo/src/generated", "/repo/src/generated/a.rs"));
+        assert!(glob_matches("/repo/**/vendor/*.rs", "/repo/src/vendor/a.rs"));
+        assert!(glob_matches("/repo/**/vendor/*.rs", "/repo/vendor/a.rs"));
+        assert!(glob_matches("/repo/src/*_pb.rs", "/repo/src/foo_pb.rs"));
+        assert!(glob_matches("/repo/src/?.rs", "/repo/src/a.rs"));
+        assert!(!glob_matches("/repo/src/*_pb.rs", "/repo/src/foo.rs"));
+        assert!(!glob_matches("/repo/src/*.rs", "/repo/src/a/b.rs"));
+        assert!(!glob_matches("/repo/src/generated/**", "/repo/src/lib.rs"));
+        assert!(!glob_matches("/repo/src/?.rs", "/repo/src/ab.rs"));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::consts::{constant_simple, Constant};
 use crate::utils::{
-    is_expn_of, match_def_path, match_qpath, match_type, method_calls, path_to_res, paths, run_lints, snippet,
-    span_lint, span_lint_and_help, span_lint_and_sugg, SpanlessEq,
+    fn_def_id, is_expn_of, match_def_path, match_qpath, match_type, method_calls, path_to_def_id, path_to_res, paths,
+    run_lints, snippet, span_lint, span_lint_and_help, span_lint_and_sugg, stable_since, SpanlessEq,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::{Crate as AstCrate, ItemKind, LitKind, NodeId};
@@ -10,16 +10,17 @@ use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{CrateNum, DefId};
 use rustc_hir::hir_id::CRATE_HIR_ID;
-use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
+use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BinOpKind, Crate, Expr, ExprKind, HirId, Item, MutTy, Mutability, Node, Path, StmtKind, Ty, TyKind, UnOp,
+    BinOpKind, Crate, Expr, ExprKind, HirId, Item, MutTy, Mutability, Node, Path, QPath, StmtKind, Ty, TyKind, UnOp,
 };
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_middle::mir::interpret::ConstValue;
 use rustc_middle::ty;
+use rustc_semver::RustcVersion;
 use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::{Span, Spanned};
 use rustc_span::symbol::{Symbol, SymbolStr};
@@ -297,6 +298,40 @@ declare_clippy_lint! {
     "unnecessary conversion between Symbol and string"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for suggestions naming standard library methods which were
+    /// stabilized after Rust 1.0.0, in functions which don't check them against the MSRV with
+    /// `utils::std_item_meets_msrv()`.
+    ///
+    /// **Why is this bad?** The suggestion doesn't compile for crates with an older MSRV.
+    ///
+    /// **Known problems:** Methods are only recognized by their name, in string literals calling
+    /// them on a formatted receiver like `"{}.as_deref()"`, and only if all the standard library
+    /// methods of that name are newer than Rust 1.0.0. So `"{}.zip(..)"` isn't linted, although
+    /// `Option::zip` is stable since Rust 1.46.0, because `Iterator::zip` is stable since Rust
+    /// 1.0.0. The check and the suggestion have to be in the same function. As the type of the receiver is unknown, suggesting a method of another
+    /// type which has the name of a newer standard library method, like `Symbol::as_str` and
+    /// `String::as_str`, is linted as well.
+    ///
+    /// **Example:**
+    /// Bad:
+    /// ```rust,ignore
+    /// let sugg = format!("{}.as_deref()", snippet(cx, recv.span, ".."));
+    /// span_lint_and_sugg(cx, LINT, expr.span, msg, "try", sugg, Applicability::MachineApplicable);
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// if std_item_meets_msrv(cx, &paths::OPTION_AS_DEREF, self.msrv.current()) {
+    ///     let sugg = format!("{}.as_deref()", snippet(cx, recv.span, ".."));
+    ///     span_lint_and_sugg(cx, LINT, expr.span, msg, "try", sugg, Applicability::MachineApplicable);
+    /// }
+    /// ```
+    pub UNCHECKED_MSRV_SUGGESTION,
+    internal,
+    "suggesting a standard library method newer than Rust 1.0.0 without checking the MSRV"
+}
+
 declare_lint_pass!(ClippyLintsInternal => [CLIPPY_LINTS_INTERNAL]);
 
 impl EarlyLintPass for ClippyLintsInternal {
@@ -833,28 +868,7 @@ fn path_to_matched_type(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<Ve
 // This is not a complete resolver for paths. It works on all the paths currently used in the paths
 // module.  That's all it does and all it needs to do.
 pub fn check_path(cx: &LateContext<'_>, path: &[&str]) -> bool {
-    if path_to_res(cx, path) != Res::Err {
-        return true;
-    }
-
-    // Some implementations can't be found by `path_to_res`, particularly inherent
-    // implementations of native types. Check lang items.
-    let path_syms: Vec<_> = path.iter().map(|p| Symbol::intern(p)).collect();
-    let lang_items = cx.tcx.lang_items();
-    for item_def_id in lang_items.items().iter().flatten() {
-        let lang_item_path = cx.get_def_path(*item_def_id);
-        if path_syms.starts_with(&lang_item_path) {
-            if let [item] = &path_syms[lang_item_path.len()..] {
-                for child in cx.tcx.item_children(*item_def_id) {
-                    if child.ident.name == *item {
-                        return true;
-                    }
-                }
-            }
-        }
-    }
-
-    false
+    path_to_def_id(cx, path).is_some()
 }
 
 declare_lint_pass!(InvalidPaths => [INVALID_PATHS]);
@@ -921,6 +935,8 @@ impl<'tcx> LateLintPass<'tcx> for InterningDefinedSymbol {
         }
     }
 
+    // the suggested `as_str` is `Symbol::as_str`
+    #[cfg_attr(feature = "internal-lints", allow(clippy::unchecked_msrv_suggestion))]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if let ExprKind::Call(func, [arg]) = &expr.kind;
@@ -1068,3 +1084,181 @@ impl<'tcx> SymbolStrExpr<'tcx> {
         }
     }
 }
+
+/// The functions which add a suggestion to a diagnostic.
+const SUGGESTION_FUNCTIONS: [&str; 9] = [
+    "multispan_sugg",
+    "multispan_sugg_with_applicability",
+    "span_lint_and_sugg",
+    "span_lint_and_sugg_for_edges",
+    "span_suggestion",
+    "span_suggestion_hidden",
+    "span_suggestion_short",
+    "span_suggestion_verbose",
+    "span_suggestions",
+];
+
+#[derive(Default)]
+pub struct UncheckedMsrvSuggestion {
+    /// The standard library methods newer than Rust 1.0.0, by name, with the version the oldest
+    /// method of that name was stabilized in. Read on the first suggestion found.
+    methods: Option<FxHashMap<Symbol, RustcVersion>>,
+}
+
+impl_lint_pass!(UncheckedMsrvSuggestion => [UNCHECKED_MSRV_SUGGESTION]);
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedMsrvSuggestion {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: intravisit::FnKind<'tcx>,
+        _: &'tcx hir::FnDecl<'_>,
+        body: &'tcx hir::Body<'_>,
+        _: Span,
+        _: HirId,
+    ) {
+        // closures are visited with the function they are in
+        if matches!(kind, intravisit::FnKind::Closure(_)) {
+            return;
+        }
+        let mut visitor = SuggestionVisitor {
+            cx,
+            suggests: false,
+            checks_msrv: false,
+            strings: Vec::new(),
+        };
+        visitor.visit_body(body);
+        if !visitor.suggests || visitor.checks_msrv {
+            return;
+        }
+
+        let methods = self.methods.get_or_insert_with(|| std_methods(cx));
+        for (span, string) in visitor.strings {
+            if let Some(name) = method_name(&string) {
+                if let Some(since) = methods.get(&Symbol::intern(name)) {
+                    span_lint_and_help(
+                        cx,
+                        UNCHECKED_MSRV_SUGGESTION,
+                        span,
+                        &format!(
+                            "this suggestion uses `{}`, which isn't stable before Rust {}",
+                            name, since
+                        ),
+                        None,
+                        "check that it is stable in the MSRV with `utils::std_item_meets_msrv()`",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Finds the suggestions, MSRV checks and string literals of a function.
+struct SuggestionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    suggests: bool,
+    checks_msrv: bool,
+    strings: Vec<(Span, SymbolStr)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SuggestionVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        let called = match &expr.kind {
+            ExprKind::Call(func, _) => match &func.kind {
+                ExprKind::Path(QPath::Resolved(_, path)) => path.segments.last().map(|segment| segment.ident.name),
+                _ => None,
+            },
+            ExprKind::MethodCall(segment, ..) => Some(segment.ident.name),
+            ExprKind::Lit(lit) => {
+                if let LitKind::Str(string, _) = lit.node {
+                    self.strings.push((expr.span, string.as_str()));
+                }
+                None
+            },
+            _ => None,
+        };
+        if let Some(called) = called {
+            self.suggests |= SUGGESTION_FUNCTIONS.contains(&&*called.as_str());
+            // not `utils::meets_msrv`, which compares the MSRV with a hand-written version
+            self.checks_msrv |= fn_def_id(self.cx, expr).map_or(false, |def_id| {
+                match_def_path(self.cx, def_id, &paths::STD_ITEM_MEETS_MSRV)
+                    || match_def_path(self.cx, def_id, &paths::STD_ITEM_METHOD_MEETS_MSRV)
+            });
+        }
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// The name of the method called on a formatted receiver in a snippet of code, like `as_deref` in
+/// `"{}.as_deref()"`. The literal pieces of `format!` strings start after the receiver, as in
+/// `".as_deref()"`.
+fn method_name(code: &str) -> Option<&str> {
+    let rest = code.strip_prefix('.').or_else(|| code.strip_prefix("{}."))?;
+    let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    (len > 0 && rest[len..].starts_with('(')).then(|| &rest[..len])
+}
+
+/// Reads the methods of the standard library newer than Rust 1.0.0, see
+/// `UncheckedMsrvSuggestion::methods`.
+fn std_methods(cx: &LateContext<'_>) -> FxHashMap<Symbol, RustcVersion> {
+    let tcx = cx.tcx;
+    let mut oldest = FxHashMap::<Symbol, RustcVersion>::default();
+    // the traits, impls and modules already read, as items are reexported in several modules
+    let mut visited = FxHashSet::default();
+    let mut add_methods = |def_id: DefId, visited: &mut FxHashSet<DefId>| {
+        if !visited.insert(def_id) {
+            return;
+        }
+        for item in tcx.associated_items(def_id).in_definition_order() {
+            if item.kind == ty::AssocKind::Fn {
+                if let Some(since) = stable_since(cx, item.def_id) {
+                    let version = oldest.entry(item.ident.name).or_insert(since);
+                    if since < *version {
+                        *version = since;
+                    }
+                }
+            }
+        }
+    };
+
+    // the inherent implementations of the primitive types
+    for &def_id in tcx.lang_items().items().iter().flatten() {
+        if tcx.def_kind(def_id) == DefKind::Impl {
+            add_methods(def_id, &mut visited);
+        }
+    }
+    // the traits and the inherent implementations of the types of the modules, recursively
+    let mut modules: Vec<DefId> = tcx
+        .crates()
+        .iter()
+        .filter(|&&krate| ["core", "alloc", "std"].contains(&&*tcx.crate_name(krate).as_str()))
+        .map(CrateNum::as_def_id)
+        .collect();
+    while let Some(module) = modules.pop() {
+        if !visited.insert(module) {
+            continue;
+        }
+        for child in tcx.item_children(module) {
+            match child.res {
+                Res::Def(DefKind::Mod, def_id) => modules.push(def_id),
+                Res::Def(DefKind::Trait, def_id) => add_methods(def_id, &mut visited),
+                Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union, def_id) => {
+                    for &impl_def_id in tcx.inherent_impls(def_id) {
+                        add_methods(impl_def_id, &mut visited);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    let first_release = RustcVersion::new(1, 0, 0);
+    oldest.retain(|_, since| !first_release.meets(*since));
+    oldest
+}
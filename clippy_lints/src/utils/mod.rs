@@ -1,15 +1,20 @@
 #[macro_use]
 pub mod sym_helper;
 
+pub mod allow_in_tests;
 #[allow(clippy::module_name_repetitions)]
 pub mod ast_utils;
 pub mod attrs;
 pub mod author;
 pub mod camel_case;
+pub mod changed_lines;
 pub mod comparisons;
 pub mod conf;
 mod diagnostics;
+pub mod disallowed_paths;
 pub mod eager_or_lazy;
+pub mod enabled_lints;
+pub mod excluded_files;
 pub mod higher;
 mod hir_utils;
 pub mod inspector;
@@ -20,9 +25,11 @@ pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
 pub mod sugg;
+pub mod timings;
 pub mod usage;
 pub mod visitors;
 
+pub use self::allow_in_tests::is_in_test_code;
 pub use self::attrs::*;
 pub use self::diagnostics::*;
 pub use self::hir_utils::{both, eq_expr_value, over, SpanlessEq, SpanlessHash};
@@ -30,6 +37,7 @@ pub use self::hir_utils::{both, eq_expr_value, over, SpanlessEq, SpanlessHash};
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::hash::BuildHasherDefault;
+use std::lazy::OnceCell;
 
 use if_chain::if_chain;
 use rustc_ast::ast::{self, Attribute, LitKind};
@@ -78,6 +86,63 @@ pub fn meets_msrv(msrv: Option<&RustcVersion>, lint_msrv: &RustcVersion) -> bool
     msrv.map_or(true, |msrv| msrv.meets(*lint_msrv))
 }
 
+/// Returns the Rust version the item `def_id` was stabilized in, according to its `#[stable]`
+/// attribute, or `None` if it is unstable or the version is unknown.
+pub fn stable_since(cx: &LateContext<'_>, def_id: DefId) -> Option<RustcVersion> {
+    match cx.tcx.lookup_stability(def_id)?.level {
+        rustc_attr::StabilityLevel::Stable { since } => RustcVersion::parse(&since.as_str()).ok(),
+        rustc_attr::StabilityLevel::Unstable { .. } => None,
+    }
+}
+
+/// Checks whether the item at the definition path `path`, e.g. a standard library method a lint
+/// suggests to use, is stable in the MSRV `msrv`. The version it was stabilized in is read from
+/// the compiler instead of being hard-coded in the lint. Items which can't be found or are
+/// unstable never meet an MSRV.
+///
+/// Lints should leave out the suggestions naming the item when this returns `false`, or not fire
+/// at all if using the item is their point. The path is resolved on each call, so lint passes which
+/// check the MSRV for every expression should use a [`StdItem`] instead.
+pub fn std_item_meets_msrv(cx: &LateContext<'_>, path: &[&str], msrv: Option<&RustcVersion>) -> bool {
+    let msrv = match msrv {
+        Some(msrv) => msrv,
+        None => return true,
+    };
+    path_to_def_id(cx, path)
+        .and_then(|def_id| stable_since(cx, def_id))
+        .map_or(false, |since| msrv.meets(since))
+}
+
+/// A standard library item whose stability is checked against the MSRV like with
+/// [`std_item_meets_msrv`], but which is only resolved on the first check. Lint passes keep it in a
+/// field.
+pub struct StdItem {
+    path: &'static [&'static str],
+    /// The version the item was stabilized in, once resolved.
+    since: OnceCell<Option<RustcVersion>>,
+}
+
+impl StdItem {
+    #[must_use]
+    pub const fn new(path: &'static [&'static str]) -> Self {
+        Self {
+            path,
+            since: OnceCell::new(),
+        }
+    }
+
+    /// Checks whether the item is stable in the MSRV `msrv`.
+    pub fn meets_msrv(&self, cx: &LateContext<'_>, msrv: Option<&RustcVersion>) -> bool {
+        let msrv = match msrv {
+            Some(msrv) => msrv,
+            None => return true,
+        };
+        self.since
+            .get_or_init(|| path_to_def_id(cx, self.path).and_then(|def_id| stable_since(cx, def_id)))
+            .map_or(false, |since| msrv.meets(since))
+    }
+}
+
 macro_rules! extract_msrv_attr {
     (LateContext) => {
         extract_msrv_attr!(@LateContext, ());
@@ -87,21 +152,25 @@ macro_rules! extract_msrv_attr {
     };
     (@$context:ident$(, $call:tt)?) => {
         fn enter_lint_attrs(&mut self, cx: &rustc_lint::$context<'tcx>, attrs: &'tcx [rustc_ast::ast::Attribute]) {
-            use $crate::utils::get_unique_inner_attr;
-            match get_unique_inner_attr(cx.sess$($call)?, attrs, "msrv") {
-                Some(msrv_attr) => {
-                    if let Some(msrv) = msrv_attr.value_str() {
-                        self.msrv = $crate::utils::parse_msrv(
-                            &msrv.to_string(),
-                            Some(cx.sess$($call)?),
-                            Some(msrv_attr.span),
-                        );
-                    } else {
-                        cx.sess$($call)?.span_err(msrv_attr.span, "bad clippy attribute");
-                    }
-                },
-                _ => (),
-            }
+            self.msrv.push_attrs(cx.sess$($call)?, attrs);
+        }
+        fn exit_lint_attrs(&mut self, cx: &rustc_lint::$context<'tcx>, attrs: &'tcx [rustc_ast::ast::Attribute]) {
+            self.msrv.pop_attrs(cx.sess$($call)?, attrs);
+        }
+    };
+}
+
+/// Keeps the `ConfStack` fields of a lint pass up to date with the `clippy::conf` attributes in
+/// scope.
+macro_rules! extract_conf_attrs {
+    ($context:ident, $($field:ident),+) => {
+        fn enter_lint_attrs(&mut self, cx: &rustc_lint::$context<'tcx>, attrs: &'tcx [rustc_ast::ast::Attribute]) {
+            let sess = rustc_lint::LintContext::sess(cx);
+            $(self.$field.push_attrs(sess, attrs);)+
+        }
+        fn exit_lint_attrs(&mut self, cx: &rustc_lint::$context<'tcx>, attrs: &'tcx [rustc_ast::ast::Attribute]) {
+            let sess = rustc_lint::LintContext::sess(cx);
+            $(self.$field.pop_attrs(sess, attrs);)+
         }
     };
 }
@@ -370,6 +439,31 @@ pub fn path_to_res(cx: &LateContext<'_>, path: &[&str]) -> Res {
     try_res!(last).res
 }
 
+/// Gets the `DefId` of the item at a path. Unlike [`path_to_res`], this also finds the items of
+/// the inherent implementations of primitive types, e.g. `["core", "str", "<impl str>", "len"]`.
+pub fn path_to_def_id(cx: &LateContext<'_>, path: &[&str]) -> Option<DefId> {
+    if let Some(def_id) = path_to_res(cx, path).opt_def_id() {
+        return Some(def_id);
+    }
+
+    // The inherent implementations of primitive types are lang items.
+    let path_syms: Vec<_> = path.iter().map(|p| Symbol::intern(p)).collect();
+    let lang_items = cx.tcx.lang_items();
+    for item_def_id in lang_items.items().iter().flatten() {
+        let lang_item_path = cx.get_def_path(*item_def_id);
+        if path_syms.starts_with(&lang_item_path) {
+            if let [item] = &path_syms[lang_item_path.len()..] {
+                for child in cx.tcx.item_children(*item_def_id) {
+                    if child.ident.name == *item {
+                        return child.res.opt_def_id();
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Convenience function to get the `DefId` of a trait by path.
 /// It could be a trait or trait alias.
 pub fn get_trait_def_id(cx: &LateContext<'_>, path: &[&str]) -> Option<DefId> {
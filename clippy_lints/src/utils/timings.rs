@@ -0,0 +1,188 @@
+//! Measures the time spent in each Clippy lint pass, for `clippy-driver --lint-timings`.
+//!
+//! The passes are wrapped in [`Timed`], which adds up the time spent in each call to the pass and
+//! records it when the pass is dropped, at the end of the lint checking of the crate.
+
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync;
+use rustc_hir as hir;
+use rustc_lint::{early_lint_methods, late_lint_methods};
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore};
+use rustc_span::symbol::{Ident, Symbol};
+use rustc_span::Span;
+use std::fmt::Write as _;
+use std::lazy::SyncLazy;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + sync::Sync + 'static>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync + 'static>;
+
+/// The time spent in each pass, by kind (`early` or `late`) and name.
+static TIMINGS: SyncLazy<Mutex<FxHashMap<(&'static str, &'static str), Duration>>> = SyncLazy::new(Mutex::default);
+
+/// Measures the time spent in the early and late passes registered after the first `early` and
+/// `late` ones.
+pub fn time_passes(store: &mut LintStore, early: usize, late: usize) {
+    let passes: Vec<_> = store.early_passes.drain(early..).collect();
+    for constructor in passes {
+        store.early_passes.push(Box::new(move || -> EarlyLintPassObject {
+            box Timed::new("early", constructor())
+        }));
+    }
+    let passes: Vec<_> = store.late_passes.drain(late..).collect();
+    for constructor in passes {
+        store.late_passes.push(Box::new(move || -> LateLintPassObject {
+            box Timed::new("late", constructor())
+        }));
+    }
+}
+
+/// A lint pass which measures the time spent in the pass it wraps.
+struct Timed<P> {
+    kind: &'static str,
+    name: &'static str,
+    pass: P,
+    time: Duration,
+}
+
+impl<P: std::ops::Deref> Timed<P>
+where
+    P::Target: LintPass,
+{
+    fn new(kind: &'static str, pass: P) -> Self {
+        Self {
+            kind,
+            name: pass.name(),
+            pass,
+            time: Duration::default(),
+        }
+    }
+}
+
+impl<P> Drop for Timed<P> {
+    fn drop(&mut self) {
+        *TIMINGS.lock().unwrap().entry((self.kind, self.name)).or_default() += self.time;
+    }
+}
+
+// `impl_lint_pass!` can't take the name of the wrapped pass.
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl<P> LintPass for Timed<P> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+macro_rules! timed_early_lint_methods {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        impl EarlyLintPass for Timed<EarlyLintPassObject> {
+            $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.time += start.elapsed();
+            })*
+        }
+    };
+}
+
+macro_rules! timed_late_lint_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        impl<$hir> LateLintPass<$hir> for Timed<LateLintPassObject> {
+            $(fn $name(&mut self, cx: &LateContext<$hir>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.time += start.elapsed();
+            })*
+        }
+    };
+}
+
+early_lint_methods!(timed_early_lint_methods, []);
+late_lint_methods!(timed_late_lint_methods, [], ['tcx]);
+
+/// Takes the timings of the passes measured so far and renders them for the crate `krate`,
+/// slowest first, as a table or as a JSON object on a single line.
+pub fn report(krate: &str, json: bool) -> String {
+    let timings: Vec<_> = TIMINGS
+        .lock()
+        .unwrap()
+        .drain()
+        .map(|((kind, name), time)| (kind, name, time))
+        .collect();
+    render(krate, timings, json)
+}
+
+fn render(krate: &str, mut timings: Vec<(&str, &str, Duration)>, json: bool) -> String {
+    timings.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| (a.0, a.1).cmp(&(b.0, b.1))));
+    let total: Duration = timings.iter().map(|(_, _, time)| *time).sum();
+
+    let mut out = String::new();
+    if json {
+        let passes: Vec<_> = timings
+            .iter()
+            .map(|(kind, name, time)| {
+                format!(
+                    r#"{{"name":"{}","kind":"{}","seconds":{}}}"#,
+                    name,
+                    kind,
+                    time.as_secs_f64()
+                )
+            })
+            .collect();
+        let _ = writeln!(
+            out,
+            r#"{{"crate":"{}","seconds":{},"passes":[{}]}}"#,
+            krate,
+            total.as_secs_f64(),
+            passes.join(",")
+        );
+    } else {
+        let millis = |time: Duration| time.as_secs_f64() * 1000.0;
+        let _ = writeln!(
+            out,
+            "Clippy lint pass timings for crate `{}`: {:.1}ms",
+            krate,
+            millis(total)
+        );
+        let _ = writeln!(out, "{:>10}  {:>6}  {:<5}  pass", "time", "share", "kind");
+        for (kind, name, time) in timings {
+            let share = if total.as_nanos() == 0 {
+                0.0
+            } else {
+                time.as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            let _ = writeln!(out, "{:>8.1}ms  {:>5.1}%  {:<5}  {}", millis(time), share, kind, name);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::render;
+    use std::time::Duration;
+
+    #[test]
+    fn report() {
+        let timings = vec![
+            ("early", "DoubleParens", Duration::from_millis(1)),
+            ("late", "Methods", Duration::from_millis(3)),
+        ];
+        assert_eq!(
+            render("foo", timings.clone(), false),
+            "\
+Clippy lint pass timings for crate `foo`: 4.0ms
+      time   share  kind   pass
+     3.0ms   75.0%  late   Methods
+     1.0ms   25.0%  early  DoubleParens
+"
+        );
+        assert_eq!(
+            render("foo", timings, true),
+            r#"{"crate":"foo","seconds":0.004,"passes":[{"name":"Methods","kind":"late","seconds":0.003},{"name":"DoubleParens","kind":"early","seconds":0.001}]}
+"#
+        );
+    }
+}
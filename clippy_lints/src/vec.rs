@@ -1,6 +1,6 @@
 use crate::consts::{constant, Constant};
 use crate::rustc_target::abi::LayoutOf;
-use crate::utils::{higher, is_copy, snippet_with_applicability, span_lint_and_sugg};
+use crate::utils::{higher, is_copy, snippet_with_applicability, span_lint_and_sugg, ConfStack};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BorrowKind, Expr, ExprKind};
@@ -10,9 +10,18 @@ use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct UselessVec {
-    pub too_large_for_stack: u64,
+    too_large_for_stack: ConfStack<u64>,
+}
+
+impl UselessVec {
+    #[must_use]
+    pub fn new(too_large_for_stack: u64) -> Self {
+        Self {
+            too_large_for_stack: ConfStack::new("too_large_for_stack", too_large_for_stack),
+        }
+    }
 }
 
 declare_clippy_lint! {
@@ -71,16 +80,18 @@ impl<'tcx> LateLintPass<'tcx> for UselessVec {
             }
         }
     }
+
+    extract_conf_attrs!(LateContext, too_large_for_stack);
 }
 
 impl UselessVec {
-    fn check_vec_macro<'tcx>(self, cx: &LateContext<'tcx>, vec_args: &higher::VecArgs<'tcx>, span: Span) {
+    fn check_vec_macro<'tcx>(&self, cx: &LateContext<'tcx>, vec_args: &higher::VecArgs<'tcx>, span: Span) {
         let mut applicability = Applicability::MachineApplicable;
         let snippet = match *vec_args {
             higher::VecArgs::Repeat(elem, len) => {
                 if let Some((Constant::Int(len_constant), _)) = constant(cx, cx.typeck_results(), len) {
                     #[allow(clippy::cast_possible_truncation)]
-                    if len_constant as u64 * size_of(cx, elem) > self.too_large_for_stack {
+                    if len_constant as u64 * size_of(cx, elem) > *self.too_large_for_stack.current() {
                         return;
                     }
 
@@ -96,7 +107,7 @@ impl UselessVec {
             higher::VecArgs::Vec(args) => {
                 if let Some(last) = args.iter().last() {
                     #[allow(clippy::cast_possible_truncation)]
-                    if args.len() as u64 * size_of(cx, last) > self.too_large_for_stack {
+                    if args.len() as u64 * size_of(cx, last) > *self.too_large_for_stack.current() {
                         return;
                     }
                     let span = args[0].span.to(last.span);
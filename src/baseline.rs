@@ -0,0 +1,229 @@
+//! Baseline files, recording existing Clippy findings so that only the new ones are reported, for
+//! `cargo clippy --write-baseline <PATH>` and `cargo clippy --baseline <PATH>`.
+//!
+//! A finding is identified by its fingerprint: the lint, the file and the path of the item it is
+//! in, and its snippet with normalized whitespace. None of these change when lines are added or
+//! removed elsewhere in the file.
+
+use crate::diagnostic::{locate, Diagnostic, Span};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+use syn::spanned::Spanned;
+
+/// The version of the baseline file format.
+const VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Fingerprint {
+    pub lint: String,
+    pub file: String,
+    /// The path of the item in its file, e.g. `Foo::bar`, empty outside of items.
+    pub item: String,
+    pub snippet: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Entry {
+    #[serde(flatten)]
+    fingerprint: Fingerprint,
+    /// How many times the same finding occurs.
+    count: usize,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+/// The findings recorded in a baseline file which haven't been matched yet.
+pub struct Baseline {
+    remaining: BTreeMap<Fingerprint, usize>,
+}
+
+impl Baseline {
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let file: BaselineFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if file.version != VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported baseline version {}", file.version),
+            ));
+        }
+
+        let mut remaining = BTreeMap::new();
+        for entry in file.entries {
+            *remaining.entry(entry.fingerprint).or_default() += entry.count;
+        }
+        Ok(Self { remaining })
+    }
+
+    /// Whether the finding is recorded in the baseline. Each recorded finding only suppresses as
+    /// many findings as it occurred when the baseline was written.
+    pub fn suppresses(&mut self, fingerprint: &Fingerprint) -> bool {
+        match self.remaining.get_mut(fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// The recorded findings that weren't found anymore, with how many times they are missing.
+    pub fn stale(&self) -> impl Iterator<Item = (&Fingerprint, usize)> {
+        self.remaining
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(fingerprint, count)| (fingerprint, *count))
+    }
+}
+
+/// Writes a baseline file recording `fingerprints`.
+pub fn write(path: &Path, fingerprints: &[Fingerprint]) -> io::Result<()> {
+    let mut counts = BTreeMap::<_, usize>::new();
+    for fingerprint in fingerprints {
+        *counts.entry(fingerprint.clone()).or_default() += 1;
+    }
+    let file = BaselineFile {
+        version: VERSION,
+        entries: counts
+            .into_iter()
+            .map(|(fingerprint, count)| Entry { fingerprint, count })
+            .collect(),
+    };
+    let mut json = serde_json::to_string_pretty(&file)?;
+    json.push('\n');
+    fs::write(path, json)
+}
+
+/// Computes the fingerprints of diagnostics, parsing each source file at most once.
+#[derive(Default)]
+pub struct Fingerprinter {
+    files: HashMap<String, Option<syn::File>>,
+}
+
+impl Fingerprinter {
+    pub fn fingerprint(&mut self, diag: &Diagnostic) -> Fingerprint {
+        let span = diag.primary_span();
+        let file = span.map(|span| span.file_name.clone()).unwrap_or_default();
+        let item = match (span, self.parse(&file)) {
+            (Some(span), Some(ast)) => {
+                let mut path = Vec::new();
+                item_path(&ast.items, span.line_start, &mut path);
+                path.join("::")
+            },
+            _ => String::new(),
+        };
+        let snippet = span.map(Span::snippet).unwrap_or_default();
+
+        Fingerprint {
+            lint: format!("clippy::{}", diag.lint().unwrap_or_default()),
+            file,
+            item,
+            snippet: snippet.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+
+    fn parse(&mut self, file: &str) -> Option<&syn::File> {
+        self.files
+            .entry(file.to_string())
+            .or_insert_with(|| {
+                let content = fs::read_to_string(locate(file)?).ok()?;
+                syn::parse_file(&content).ok()
+            })
+            .as_ref()
+    }
+}
+
+/// Pushes to `path` the names of the nested items of `items` containing `line`.
+fn item_path(items: &[syn::Item], line: usize, path: &mut Vec<String>) {
+    let contains = |span: proc_macro2::Span| span.start().line <= line && line <= span.end().line;
+    let item = match items.iter().find(|item| contains(item.span())) {
+        Some(item) => item,
+        None => return,
+    };
+
+    match item {
+        syn::Item::Impl(item) => {
+            let ty = &item.self_ty;
+            path.push(quote::quote!(#ty).to_string().replace(' ', ""));
+            let name = item
+                .items
+                .iter()
+                .find(|item| contains(item.span()))
+                .and_then(|item| match item {
+                    syn::ImplItem::Method(method) => Some(&method.sig.ident),
+                    syn::ImplItem::Const(constant) => Some(&constant.ident),
+                    syn::ImplItem::Type(ty) => Some(&ty.ident),
+                    _ => None,
+                });
+            path.extend(name.map(ToString::to_string));
+        },
+        syn::Item::Trait(item) => {
+            path.push(item.ident.to_string());
+            let name = item
+                .items
+                .iter()
+                .find(|item| contains(item.span()))
+                .and_then(|item| match item {
+                    syn::TraitItem::Method(method) => Some(&method.sig.ident),
+                    syn::TraitItem::Const(constant) => Some(&constant.ident),
+                    syn::TraitItem::Type(ty) => Some(&ty.ident),
+                    _ => None,
+                });
+            path.extend(name.map(ToString::to_string));
+        },
+        syn::Item::Mod(item) => {
+            path.push(item.ident.to_string());
+            if let Some((_, items)) = &item.content {
+                item_path(items, line, path);
+            }
+        },
+        syn::Item::Const(item) => path.push(item.ident.to_string()),
+        syn::Item::Enum(item) => path.push(item.ident.to_string()),
+        syn::Item::Fn(item) => path.push(item.sig.ident.to_string()),
+        syn::Item::Static(item) => path.push(item.ident.to_string()),
+        syn::Item::Struct(item) => path.push(item.ident.to_string()),
+        syn::Item::Type(item) => path.push(item.ident.to_string()),
+        syn::Item::Union(item) => path.push(item.ident.to_string()),
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::item_path;
+
+    #[test]
+    fn item_paths() {
+        let ast = syn::parse_file(
+            "fn main() {}
+
+            struct Foo;
+
+            impl Foo {
+                fn bar(&self) {
+                    let _ = 1;
+                }
+            }
+
+            mod tests {
+                fn it_works() {}
+            }",
+        )
+        .unwrap();
+        let path = |line| {
+            let mut path = Vec::new();
+            item_path(&ast.items, line, &mut path);
+            path.join("::")
+        };
+        assert_eq!(path(1), "main");
+        assert_eq!(path(2), "");
+        assert_eq!(path(7), "Foo::bar");
+        assert_eq!(path(12), "tests::it_works");
+    }
+}
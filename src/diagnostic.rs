@@ -0,0 +1,235 @@
+//! The diagnostics emitted by cargo with `--message-format=json`.
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::env;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
+/// A message of cargo's JSON output. Only the compiler messages are of interest here.
+#[derive(Deserialize)]
+struct Message {
+    reason: String,
+    message: Option<Diagnostic>,
+}
+
+/// A diagnostic emitted by the compiler, as documented in
+/// <https://doc.rust-lang.org/rustc/json.html>.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<Code>,
+    pub level: String,
+    pub spans: Vec<Span>,
+    pub children: Vec<Diagnostic>,
+    pub rendered: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub struct Code {
+    pub code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub label: Option<String>,
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<String>,
+    #[serde(default)]
+    pub text: Vec<SpanLine>,
+}
+
+/// A line of source code covered by a span.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub struct SpanLine {
+    pub text: String,
+    /// The 1-based column of the first character of the line in the span.
+    pub highlight_start: usize,
+    /// The 1-based column after the last character of the line in the span.
+    pub highlight_end: usize,
+}
+
+impl Span {
+    /// The source code covered by the span.
+    pub fn snippet(&self) -> String {
+        self.text
+            .iter()
+            .map(|line| {
+                line.text
+                    .chars()
+                    .skip(line.highlight_start.saturating_sub(1))
+                    .take(line.highlight_end.saturating_sub(line.highlight_start))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A suggested replacement for a span, along with the message of the diagnostic suggesting it.
+pub struct Suggestion<'a> {
+    pub message: &'a str,
+    pub span: &'a Span,
+    pub replacement: &'a str,
+    pub applicability: &'a str,
+}
+
+/// The link to the documentation of a lint.
+pub fn docs_link(lint: &str) -> String {
+    format!("https://rust-lang.github.io/rust-clippy/master/index.html#{}", lint)
+}
+
+impl Diagnostic {
+    /// The name of the Clippy lint that emitted this diagnostic, without the `clippy::` prefix.
+    pub fn lint(&self) -> Option<&str> {
+        self.code.as_ref()?.code.strip_prefix("clippy::")
+    }
+
+    /// The group of the lint, e.g. `style`.
+    pub fn group(&self) -> Option<&'static str> {
+        crate::explain::group(self.lint()?)
+    }
+
+    /// The link to the documentation of the lint.
+    pub fn docs_link(&self) -> Option<String> {
+        self.lint().map(docs_link)
+    }
+
+    /// Whether the diagnostic is a compiler error, meaning that the code doesn't compile. The lints
+    /// denied with e.g. `-D warnings` are errors as well, but the code compiles.
+    pub fn is_compile_error(&self) -> bool {
+        if !self.level.starts_with("error") || self.message.starts_with("aborting due to") {
+            return false;
+        }
+        // the errors have codes like `E0308` or none, the lints have their names
+        self.code.as_ref().map_or(true, |code| {
+            code.code.len() == 5 && code.code.starts_with('E') && code.code[1..].bytes().all(|b| b.is_ascii_digit())
+        })
+    }
+
+    /// Whether the diagnostic is one of rustc's summaries of the diagnostics of a crate, like
+    /// `aborting due to 2 previous errors` or `3 warnings emitted`.
+    pub fn is_summary(&self) -> bool {
+        self.code.is_none()
+            && self.spans.is_empty()
+            && (self.message.starts_with("aborting due to") || self.message.ends_with(" emitted"))
+    }
+
+    /// The span the diagnostic is reported at.
+    pub fn primary_span(&self) -> Option<&Span> {
+        self.spans
+            .iter()
+            .find(|span| span.is_primary)
+            .or_else(|| self.spans.first())
+    }
+
+    /// The replacements suggested by the diagnostic and its children.
+    pub fn suggestions(&self) -> Vec<Suggestion<'_>> {
+        let mut suggestions = Vec::new();
+        for diag in Some(self).into_iter().chain(&self.children) {
+            for span in &diag.spans {
+                if let Some(replacement) = &span.suggested_replacement {
+                    suggestions.push(Suggestion {
+                        message: &diag.message,
+                        span,
+                        replacement,
+                        applicability: span.suggestion_applicability.as_deref().unwrap_or("Unspecified"),
+                    });
+                }
+            }
+        }
+        suggestions
+    }
+}
+
+/// Finds a file named in a diagnostic. Cargo runs the compiler in the workspace root, which is the
+/// current directory or one of its parents.
+pub fn locate(file: &str) -> Option<PathBuf> {
+    let dir = env::current_dir().ok()?;
+    dir.ancestors().map(|dir| dir.join(file)).find(|path| path.is_file())
+}
+
+/// Parses a line of cargo's JSON output. Lines that aren't JSON, e.g. the output of a build script,
+/// are printed to stderr.
+fn parse(line: &str) -> Option<Diagnostic> {
+    match serde_json::from_str::<Message>(line) {
+        Ok(Message {
+            reason,
+            message: Some(diag),
+        }) if reason == "compiler-message" => Some(diag),
+        Ok(_) => None,
+        Err(_) => {
+            eprintln!("{}", line);
+            None
+        },
+    }
+}
+
+/// Reads cargo's JSON output, printing the rendered compiler messages to stderr like cargo would
+/// and collecting the Clippy diagnostics. Stdout is left for the report.
+///
+/// The compiler messages for which `keep` returns `false` are neither printed nor collected.
+/// Identical Clippy diagnostics, e.g. for a module shared by several targets, are only reported
+/// once.
+pub fn read(output: impl BufRead, mut keep: impl FnMut(&Diagnostic) -> bool) -> io::Result<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    let mut seen = HashSet::new();
+    let mut rendered = HashSet::new();
+    for line in output.lines() {
+        let diag = match parse(&line?) {
+            Some(diag) => diag,
+            None => continue,
+        };
+
+        let lint = diag.lint().is_some();
+        if lint && !seen.insert(diag.clone()) {
+            continue;
+        }
+        if !keep(&diag) {
+            continue;
+        }
+        if lint {
+            diagnostics.push(diag.clone());
+        }
+        if let Some(text) = diag.rendered {
+            if !rendered.contains(&text) {
+                eprint!("{}", text);
+                rendered.insert(text);
+            }
+        }
+    }
+    Ok(diagnostics)
+}
+
+/// Reads all the compiler messages of cargo's JSON output without printing them. Identical
+/// Clippy diagnostics are only collected once, like with [`read`].
+pub fn read_all(output: impl BufRead) -> io::Result<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    let mut seen = HashSet::new();
+    for line in output.lines() {
+        if let Some(diag) = parse(&line?) {
+            if diag.lint().is_none() || seen.insert(diag.clone()) {
+                diagnostics.push(diag);
+            }
+        }
+    }
+    Ok(diagnostics)
+}
+
+/// Prints the rendered compiler messages to stderr like cargo would, each distinct one once.
+pub fn print(diagnostics: &[Diagnostic]) {
+    let mut rendered = HashSet::new();
+    for text in diagnostics.iter().filter_map(|diag| diag.rendered.as_ref()) {
+        if rendered.insert(text) {
+            eprint!("{}", text);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Unified diffs of the edits made by `cargo clippy --fix`.
+
+use crate::fix::Edit;
+
+/// The number of unchanged lines shown around the changed ones.
+const CONTEXT: usize = 3;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+pub const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders `text` in the terminal style `style` if `color` is set.
+pub fn paint(style: &str, text: &str, color: bool) -> String {
+    if color {
+        format!("{}{}{}", style, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+/// The changed lines `start..end` of a file, 0-based, and the edits in them.
+struct Region<'e> {
+    start: usize,
+    end: usize,
+    edits: Vec<&'e Edit>,
+}
+
+/// Renders the unified diff of the `edits` of the file `file` with the content `content`, as
+/// `git diff` would. The edits must not overlap.
+pub fn unified(file: &str, content: &str, edits: &[&Edit], color: bool) -> String {
+    // the byte offset of the start of each line, and of the end of the file
+    let mut lines: Vec<usize> = Some(0)
+        .into_iter()
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .filter(|&i| i < content.len())
+        .collect();
+    lines.push(content.len());
+    let line_of = |pos: usize| lines.iter().rposition(|&start| start <= pos).unwrap_or(0);
+    let line = |i: usize| &content[lines[i]..lines[i + 1]];
+
+    let mut edits = edits.to_vec();
+    edits.sort_by_key(|edit| (edit.start, edit.end));
+    let mut regions: Vec<Region<'_>> = Vec::new();
+    for edit in edits {
+        let start = line_of(edit.start).min(lines.len() - 1);
+        let end = if edit.end > edit.start {
+            line_of(edit.end - 1) + 1
+        } else {
+            (start + 1).min(lines.len() - 1)
+        };
+        match regions.last_mut() {
+            Some(region) if start < region.end => {
+                region.end = region.end.max(end);
+                region.edits.push(edit);
+            },
+            _ => regions.push(Region {
+                start,
+                end,
+                edits: vec![edit],
+            }),
+        }
+    }
+
+    let mut diff = paint(BOLD, &format!("--- a/{}\n+++ b/{}", file, file), color);
+    diff.push('\n');
+    // the number of lines added and removed by the previous hunks
+    let (mut added, mut removed) = (0, 0);
+    let mut i = 0;
+    while i < regions.len() {
+        // the regions shown in the same hunk, as their context lines overlap
+        let mut j = i + 1;
+        while j < regions.len() && regions[j].start <= regions[j - 1].end + 2 * CONTEXT {
+            j += 1;
+        }
+        let hunk = &regions[i..j];
+        let first = hunk[0].start.saturating_sub(CONTEXT);
+        let last = (hunk[hunk.len() - 1].end + CONTEXT).min(lines.len() - 1);
+
+        let mut body = String::new();
+        let old_len = last - first;
+        let mut new_len = old_len;
+        let unchanged = |body: &mut String, from: usize, to: usize| {
+            for i in from..to {
+                push_line(body, " ", line(i), None);
+            }
+        };
+        unchanged(&mut body, first, hunk[0].start);
+        for (k, region) in hunk.iter().enumerate() {
+            let old = &content[lines[region.start]..lines[region.end]];
+            let mut new = String::new();
+            let mut pos = lines[region.start];
+            for edit in &region.edits {
+                new.push_str(&content[pos..edit.start]);
+                new.push_str(&edit.replacement);
+                pos = edit.end;
+            }
+            new.push_str(&content[pos..lines[region.end]]);
+
+            for old_line in old.split_inclusive('\n') {
+                push_line(&mut body, "-", old_line, color.then(|| RED));
+            }
+            for new_line in new.split_inclusive('\n') {
+                push_line(&mut body, "+", new_line, color.then(|| GREEN));
+            }
+            new_len = new_len + new.split_inclusive('\n').count() - (region.end - region.start);
+
+            let next = hunk.get(k + 1).map_or(last, |next| next.start);
+            unchanged(&mut body, region.end, next);
+        }
+        let new_start = first + added + 1 - removed;
+        diff.push_str(&paint(
+            CYAN,
+            &format!("@@ -{},{} +{},{} @@", first + 1, old_len, new_start, new_len),
+            color,
+        ));
+        diff.push('\n');
+        diff.push_str(&body);
+        added += new_len;
+        removed += old_len;
+        i = j;
+    }
+    diff
+}
+
+/// Pushes a line of a diff, with `prefix` and in the given color.
+fn push_line(diff: &mut String, prefix: &str, line: &str, color: Option<&str>) {
+    let text = line.strip_suffix('\n').unwrap_or(line);
+    match color {
+        Some(color) => diff.push_str(&format!("{}{}{}{}\n", color, prefix, text, RESET)),
+        None => diff.push_str(&format!("{}{}\n", prefix, text)),
+    }
+    if !line.ends_with('\n') {
+        diff.push_str("\\ No newline at end of file\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unified;
+    use crate::fix::Edit;
+
+    #[test]
+    fn diff() {
+        let content = "fn a() -> i32 {\n    let x = 1;\n    return x;\n}\n";
+        let edit = Edit {
+            start: 35,
+            end: 44,
+            replacement: "x".into(),
+        };
+        assert_eq!(&content[35..44], "return x;");
+        assert_eq!(
+            unified("src/main.rs", content, &[&edit], false),
+            "\
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,4 +1,4 @@
+ fn a() -> i32 {
+     let x = 1;
+-    return x;
++    x
+ }
+"
+        );
+    }
+
+    #[test]
+    fn diff_hunks() {
+        let content: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        let edit = |line: &str, replacement: &str| {
+            let start = content.find(line).unwrap();
+            Edit {
+                start,
+                end: start + line.len(),
+                replacement: replacement.into(),
+            }
+        };
+        // the first edit adds a line, which moves the second hunk
+        let first = edit("line 2\n", "line 2a\nline 2b\n");
+        let second = edit("line 17", "line 17 changed");
+        assert_eq!(
+            unified("src/lib.rs", &content, &[&second, &first], false),
+            "\
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,5 +1,6 @@
+ line 1
+-line 2
++line 2a
++line 2b
+ line 3
+ line 4
+ line 5
+@@ -14,7 +15,7 @@
+ line 14
+ line 15
+ line 16
+-line 17
++line 17 changed
+ line 18
+ line 19
+ line 20
+"
+        );
+    }
+
+    #[test]
+    fn diff_without_trailing_newline() {
+        let content = "fn a() -> i32 {\n    let x = 1;\n    return x; }";
+        let start = content.find("return x;").unwrap();
+        let edit = Edit {
+            start,
+            end: start + "return x;".len(),
+            replacement: "x".into(),
+        };
+        assert_eq!(
+            unified("src/lib.rs", content, &[&edit], false),
+            "\
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ fn a() -> i32 {
+     let x = 1;
+-    return x; }
+\\ No newline at end of file
++    x }
+\\ No newline at end of file
+"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 #![feature(rustc_private)]
+#![feature(backtrace)]
 #![feature(once_cell)]
 #![cfg_attr(feature = "deny-warnings", deny(warnings))]
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
@@ -8,17 +9,24 @@
 
 // FIXME: switch to something more ergonomic here, once available.
 // (Currently there is no way to opt into sysroot crates without `extern crate`.)
+extern crate rustc_data_structures;
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
 extern crate rustc_middle;
+extern crate rustc_session;
+extern crate rustc_span;
 
-use rustc_interface::interface;
+mod ice;
+
+use rustc_interface::{interface, Queries};
 use rustc_middle::ty::TyCtxt;
 use rustc_tools_util::VersionInfo;
 
 use std::borrow::Cow;
 use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::lazy::SyncLazy;
 use std::ops::Deref;
 use std::panic;
@@ -64,10 +72,28 @@ fn test_arg_value() {
 struct DefaultCallbacks;
 impl rustc_driver::Callbacks for DefaultCallbacks {}
 
-struct ClippyCallbacks;
+struct ClippyCallbacks {
+    print_conf: bool,
+    profile: Option<String>,
+    changed_since: Option<String>,
+    changed_items: bool,
+    lint_timings: Option<String>,
+}
+
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
+        // Lint levels from the configuration file have a lower priority than the ones passed on the
+        // command line, so they come first.
+        let mut lint_opts = clippy_lints::read_conf_lint_levels(self.profile.as_deref());
+        lint_opts.append(&mut config.opts.lint_opts);
+        config.opts.lint_opts = lint_opts;
+
         let previous = config.register_lints.take();
+        let print_conf = self.print_conf;
+        let profile = self.profile.take();
+        let changed_since = self.changed_since.take();
+        let changed_items = self.changed_items;
+        let lint_timings = self.lint_timings.clone();
         config.register_lints = Some(Box::new(move |sess, mut lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
             // is there already. Certainly it can't hurt.
@@ -75,8 +101,55 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 (previous)(sess, lint_store);
             }
 
+            // Cargo replays the diagnostics of the crates that are up to date, which is only right
+            // if they were checked with the same Clippy arguments.
+            if let Ok(clippy_args) = env::var("CLIPPY_ARGS") {
+                sess.parse_sess.env_depinfo.lock().insert((
+                    rustc_span::Symbol::intern("CLIPPY_ARGS"),
+                    Some(rustc_span::Symbol::intern(&clippy_args)),
+                ));
+            }
+
             let conf = clippy_lints::read_conf(&[], &sess);
-            clippy_lints::register_plugins(&mut lint_store, &sess, &conf);
+            ice::record_conf(&conf);
+            ice::record_source_map(sess.parse_sess.clone_source_map());
+            if let Some(profile) = &profile {
+                if !conf.profile.contains_key(profile) {
+                    sess.err(&format!("unknown Clippy profile `{}`", profile));
+                }
+            }
+            if print_conf {
+                for (key, source) in &conf.sources {
+                    let files: Vec<_> = source
+                        .files
+                        .iter()
+                        .map(|file| format!("`{}`", file.display()))
+                        .collect();
+                    sess.note_without_error(&format!(
+                        "Clippy configuration `{} = {}` read from {}",
+                        key,
+                        source.value,
+                        files.join(", ")
+                    ));
+                }
+            }
+            if let Some(rev) = &changed_since {
+                clippy_lints::register_changed_lines(&mut lint_store, &sess, rev, changed_items);
+            }
+            if let Some(value) = &lint_timings {
+                let (format, _) = lint_timings_target(value);
+                if format != "table" && format != "json" {
+                    sess.err(&format!(
+                        "unknown lint timings format `{}`, expected `table` or `json`",
+                        format
+                    ));
+                }
+                clippy_lints::register_timed(&mut lint_store, |store| {
+                    clippy_lints::register_plugins(store, &sess, &conf);
+                });
+            } else {
+                clippy_lints::register_plugins(&mut lint_store, &sess, &conf);
+            }
             clippy_lints::register_pre_expansion_lints(&mut lint_store);
             clippy_lints::register_renamed(&mut lint_store);
         }));
@@ -86,9 +159,60 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
         // MIR passes can be enabled / disabled separately, we should figure out, what passes to
         // use for Clippy.
         config.opts.debugging_opts.mir_opt_level = 0;
+
+        config.file_loader = Some(Box::new(ice::RecordingFileLoader::new(config.file_loader.take())));
+    }
+
+    fn after_expansion<'tcx>(
+        &mut self,
+        compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> rustc_driver::Compilation {
+        if let Ok(expansion) = queries.expansion() {
+            let (krate, _, lint_store) = &*expansion.peek();
+            clippy_lints::read_enabled_lints(compiler.session(), lint_store, krate);
+        }
+        rustc_driver::Compilation::Continue
+    }
+
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> rustc_driver::Compilation {
+        if let Some(value) = &self.lint_timings {
+            let (format, path) = lint_timings_target(value);
+            let krate = queries.crate_name().map(|name| name.peek().clone()).unwrap_or_default();
+            let report = clippy_lints::lint_timings(&krate, format == "json");
+            // Cargo passes the standard output of rustc through to its own, which carries its JSON
+            // messages with `--message-format=json`, so the report can't go there.
+            match path {
+                Some(path) => {
+                    let written = OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(path)
+                        .and_then(|mut file| file.write_all(report.as_bytes()));
+                    if let Err(error) = written {
+                        compiler
+                            .session()
+                            .err(&format!("couldn't write the lint timings to `{}`: {}", path, error));
+                    }
+                },
+                None => eprint!("{}", report),
+            }
+        }
+        rustc_driver::Compilation::Continue
     }
 }
 
+/// Splits the value of `--lint-timings` into the format of the report and the file to append it
+/// to, if it is given after a colon.
+fn lint_timings_target(value: &str) -> (&str, Option<&str>) {
+    let mut parts = value.splitn(2, ':');
+    (parts.next().unwrap_or_default(), parts.next())
+}
+
 fn display_help() {
     println!(
         "\
@@ -163,6 +287,18 @@ fn report_clippy_ice(info: &panic::PanicInfo<'_>, bug_report_url: &str) {
         handler.note_without_error(&note);
     }
 
+    match ice::write_bundle(info, &version_info.to_string()) {
+        Ok(dir) => handler.note_without_error(&format!(
+            "a bundle to reproduce the crash was written to `{}`, please review it and attach it to the bug \
+             report: it contains the source files of the crate",
+            dir.display()
+        )),
+        Err(error) => handler.note_without_error(&format!(
+            "the bundle to reproduce the crash could not be written: {}",
+            error
+        )),
+    }
+
     // If backtraces are enabled, also print the query stack
     let backtrace = env::var_os("RUST_BACKTRACE").map_or(false, |x| &x != "0");
 
@@ -279,6 +415,11 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut print_conf = false;
+        let mut profile = None;
+        let mut changed_since = None;
+        let mut changed_items = false;
+        let mut lint_timings = None;
         let clippy_args = env::var("CLIPPY_ARGS")
             .unwrap_or_default()
             .split("__CLIPPY_HACKERY__")
@@ -288,6 +429,30 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
+                "--print-conf" => {
+                    print_conf = true;
+                    None
+                },
+                "--changed-items" => {
+                    changed_items = true;
+                    None
+                },
+                "--lint-timings" => {
+                    lint_timings = Some("table".to_string());
+                    None
+                },
+                _ if s.starts_with("--clippy-profile=") => {
+                    profile = Some(s["--clippy-profile=".len()..].to_string());
+                    None
+                },
+                _ if s.starts_with("--changed-since=") => {
+                    changed_since = Some(s["--changed-since=".len()..].to_string());
+                    None
+                },
+                _ if s.starts_with("--lint-timings=") => {
+                    lint_timings = Some(s["--lint-timings=".len()..].to_string());
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
@@ -307,11 +472,19 @@ pub fn main() {
             args.extend(clippy_args);
         }
 
-        let mut clippy = ClippyCallbacks;
+        let mut clippy = ClippyCallbacks {
+            print_conf,
+            profile,
+            changed_since,
+            changed_items,
+            lint_timings,
+        };
         let mut default = DefaultCallbacks;
         let callbacks: &mut (dyn rustc_driver::Callbacks + Send) =
             if clippy_enabled { &mut clippy } else { &mut default };
 
+        ice::record_args(&args);
+
         rustc_driver::RunCompiler::new(&args, callbacks).run()
     }))
 }
@@ -0,0 +1,146 @@
+//! Offline documentation of the lints for `cargo clippy --explain <lint>`.
+
+/// The documentation of a lint, extracted from its declaration by `build.rs`.
+struct LintDoc {
+    name: &'static str,
+    group: &'static str,
+    docs: &'static str,
+    config: &'static [Config],
+}
+
+/// A configuration value of `clippy.toml` that affects a lint.
+struct Config {
+    name: &'static str,
+    ty: &'static str,
+    default: &'static str,
+    doc: &'static str,
+}
+
+const LINTS: &[LintDoc] = include!(concat!(env!("OUT_DIR"), "/lint_docs.rs"));
+
+/// The default level of the lints of a group.
+fn default_level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "deny",
+        "style" | "complexity" | "perf" => "warn",
+        "deprecated" => "none",
+        _ => "allow",
+    }
+}
+
+/// The group of the lint `name`, e.g. `style`.
+pub fn group(name: &str) -> Option<&'static str> {
+    LINTS.iter().find(|lint| lint.name == name).map(|lint| lint.group)
+}
+
+/// The first paragraph of the documentation of the lint `name`, saying what it does.
+pub fn what_it_does(name: &str) -> Option<String> {
+    let docs = LINTS.iter().find(|lint| lint.name == name)?.docs;
+    let paragraph = docs.split("\n\n").next()?.trim_start_matches("**What it does:**");
+    Some(paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// The Levenshtein distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let mut row: Vec<usize> = (0..=b.chars().count()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.chars().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a == b {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[row.len() - 1]
+}
+
+/// Normalizes a lint name as it may be written on the command line, e.g.
+/// `clippy::needless-collect`.
+fn normalize(name: &str) -> String {
+    name.trim_start_matches("clippy::").replace('-', "_").to_lowercase()
+}
+
+/// The lints whose name is close to `name`, closest first.
+fn similar_lints(name: &str) -> Vec<&'static str> {
+    let max_dist = std::cmp::max(name.len(), 3) / 3;
+    let mut similar: Vec<_> = LINTS
+        .iter()
+        .map(|lint| (distance(name, lint.name), lint.name))
+        .filter(|&(dist, _)| dist <= max_dist)
+        .collect();
+    similar.sort_unstable();
+    similar.into_iter().map(|(_, name)| name).take(3).collect()
+}
+
+/// The lint `name`, written as on the command line. If there is no such lint, an error is printed
+/// suggesting the lints with a similar name instead.
+fn find(name: &str) -> Option<&'static LintDoc> {
+    let name = normalize(name);
+    let lint = LINTS.iter().find(|lint| lint.name == name);
+    if lint.is_none() {
+        eprintln!("error: unknown lint `{}`", name);
+        match similar_lints(&name).as_slice() {
+            [] => {},
+            [similar] => eprintln!("help: did you mean `{}`?", similar),
+            similar => eprintln!("help: did you mean one of `{}`?", similar.join("`, `")),
+        }
+    }
+    lint
+}
+
+/// The name of the lint `name`, without the `clippy::` prefix. If there is no such lint, an error
+/// is printed suggesting the lints with a similar name instead.
+pub fn lint_name(name: &str) -> Option<&'static str> {
+    find(name).map(|lint| lint.name)
+}
+
+/// Prints the documentation of the lint `name`.
+///
+/// If there is no such lint, the lints with a similar name are suggested instead.
+pub fn explain(name: &str) -> Result<(), i32> {
+    let lint = find(name).ok_or(1)?;
+
+    println!(
+        "clippy::{} (group: {}, default level: {})\n",
+        lint.name,
+        lint.group,
+        default_level(lint.group)
+    );
+    println!("{}", lint.docs);
+    if !lint.config.is_empty() {
+        println!("\n**Configuration:** This lint can be configured in `clippy.toml`:");
+        for config in lint.config {
+            println!(
+                "* `{}: {}`: {} (defaults to `{}`)",
+                config.name, config.ty, config.doc, config.default
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, similar_lints, LINTS};
+
+    #[test]
+    fn lints_are_embedded() {
+        let lint = LINTS.iter().find(|lint| lint.name == "too_many_lines").unwrap();
+        assert_eq!(lint.group, "pedantic");
+        assert!(lint.docs.starts_with("**What it does:**"));
+        assert_eq!(lint.config[0].name, "too-many-lines-threshold");
+        assert_eq!(lint.config[0].default, "100");
+    }
+
+    #[test]
+    fn fuzzy_match() {
+        assert_eq!(normalize("clippy::Needless-Collect"), "needless_collect");
+        assert_eq!(similar_lints("needles_colect")[0], "needless_collect");
+        assert!(similar_lints("completely_unrelated_name").is_empty());
+    }
+}
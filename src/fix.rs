@@ -0,0 +1,653 @@
+//! Applying the suggestions of the Clippy lints to the source files, for `cargo clippy --fix`.
+//!
+//! The code is checked again once the suggestions are applied, and the original files are restored
+//! if it doesn't compile anymore.
+
+use crate::diagnostic::{self, Diagnostic};
+use crate::{diff, explain};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// How sure the lints must be of their suggestions for them to be applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Applicability {
+    /// Only the suggestions known to be correct.
+    MachineApplicable,
+    /// Also the suggestions which may be incorrect, e.g. change the behavior of the code.
+    MaybeIncorrect,
+}
+
+impl Applicability {
+    /// The names of the applicabilities on the command line.
+    pub const NAMES: [&'static str; 2] = ["machine-applicable", "maybe-incorrect"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "machine-applicable" => Some(Self::MachineApplicable),
+            "maybe-incorrect" => Some(Self::MaybeIncorrect),
+            _ => None,
+        }
+    }
+
+    /// Whether the suggestions with the applicability `name`, as in the compiler's JSON output,
+    /// are applied.
+    fn allows(self, name: &str) -> bool {
+        match name {
+            "MachineApplicable" => true,
+            "MaybeIncorrect" => self == Self::MaybeIncorrect,
+            _ => false,
+        }
+    }
+}
+
+/// Which suggestions `cargo clippy --fix` applies.
+#[derive(Debug)]
+pub struct Options {
+    pub applicability: Applicability,
+    /// The lints whose suggestions are applied, without the `clippy::` prefix, or all of them if
+    /// empty.
+    pub lints: Vec<&'static str>,
+    /// Whether the user is asked to accept each suggestion.
+    pub interactive: bool,
+    /// Whether the suggestions are applied even if the code doesn't compile, and kept if it doesn't
+    /// compile with them.
+    pub broken_code: bool,
+}
+
+/// A replacement of the bytes `start..end` of a file.
+#[derive(Debug, PartialEq)]
+pub struct Edit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+impl Edit {
+    fn overlaps(&self, other: &Self) -> bool {
+        // two insertions at the same position conflict as well
+        (self.start < other.end && other.start < self.end) || self.start == other.start
+    }
+}
+
+/// The edits of a suggestion, which are applied together.
+#[derive(Debug)]
+pub struct Fix<'a> {
+    pub diag: &'a Diagnostic,
+    pub lint: &'a str,
+    /// The message of the suggestion, e.g. `remove `return``.
+    pub message: &'a str,
+    /// The file as named in the diagnostic.
+    pub file: &'a str,
+    pub edits: Vec<Edit>,
+}
+
+impl Fix<'_> {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.file == other.file
+            && self
+                .edits
+                .iter()
+                .any(|edit| other.edits.iter().any(|other| edit.overlaps(other)))
+    }
+
+    /// Whether the edits are within `content` and at character boundaries.
+    fn fits(&self, content: &str) -> bool {
+        self.edits.iter().all(|edit| {
+            edit.start <= edit.end
+                && edit.end <= content.len()
+                && content.is_char_boundary(edit.start)
+                && content.is_char_boundary(edit.end)
+        })
+    }
+}
+
+impl Options {
+    /// The fix for `diag`, from the first of its suggestions that is applicable enough. The spans
+    /// of a suggestion must all be in the same file.
+    pub fn fix<'a>(&self, diag: &'a Diagnostic) -> Option<Fix<'a>> {
+        let lint = diag.lint()?;
+        if !self.lints.is_empty() && !self.lints.contains(&lint) {
+            return None;
+        }
+
+        diag.children.iter().find_map(|suggestion| {
+            let spans: Vec<_> = suggestion
+                .spans
+                .iter()
+                .filter(|span| span.suggested_replacement.is_some())
+                .collect();
+            let file = &spans.first()?.file_name;
+            let applicable = spans.iter().all(|span| {
+                &span.file_name == file
+                    && self
+                        .applicability
+                        .allows(span.suggestion_applicability.as_deref().unwrap_or_default())
+            });
+            if !applicable {
+                return None;
+            }
+            Some(Fix {
+                diag,
+                lint,
+                message: &suggestion.message,
+                file,
+                edits: spans
+                    .iter()
+                    .map(|span| Edit {
+                        start: span.byte_start,
+                        end: span.byte_end,
+                        replacement: span.suggested_replacement.clone().unwrap_or_default(),
+                    })
+                    .collect(),
+            })
+        })
+    }
+}
+
+/// Returns `content` with the edits of `fixes`, which must not overlap.
+pub fn edit(content: &str, fixes: &[&Fix<'_>]) -> String {
+    let mut edits: Vec<&Edit> = fixes.iter().flat_map(|fix| &fix.edits).collect();
+    edits.sort_by_key(|edit| (edit.start, edit.end));
+
+    let mut edited = String::with_capacity(content.len());
+    let mut pos = 0;
+    for edit in edits {
+        edited.push_str(&content[pos..edit.start]);
+        edited.push_str(&edit.replacement);
+        pos = edit.end;
+    }
+    edited.push_str(&content[pos..]);
+    edited
+}
+
+/// The original content of the files changed by the fixes.
+#[derive(Default)]
+pub struct Originals {
+    files: Vec<(PathBuf, String)>,
+}
+
+impl Originals {
+    /// Writes back the original content of the changed files.
+    pub fn restore(&self) -> io::Result<()> {
+        for (path, content) in &self.files {
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the fixes chosen by `choose`, which is given the content of their file, to the source
+/// files. A fix is skipped without asking `choose` if it overlaps a fix which is applied before it,
+/// or doesn't match the file anymore.
+///
+/// Returns the applied fixes and the original content of the changed files. The files are restored
+/// if one of them can't be read or written.
+pub fn apply<'a, 'f>(
+    fixes: &'f [Fix<'a>],
+    mut choose: impl FnMut(&Fix<'a>, &str) -> bool,
+) -> io::Result<(Vec<&'f Fix<'a>>, Originals)> {
+    let mut by_file = BTreeMap::<&str, Vec<&Fix<'_>>>::new();
+    for fix in fixes {
+        by_file.entry(fix.file).or_default().push(fix);
+    }
+
+    let mut applied = Vec::new();
+    let mut originals = Originals::default();
+    let result = by_file.into_iter().try_for_each(|(file, fixes)| {
+        let path = match diagnostic::locate(file) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let content = fs::read_to_string(&path)?;
+
+        let mut chosen: Vec<&Fix<'_>> = Vec::new();
+        for fix in fixes {
+            if fix.fits(&content) && !chosen.iter().any(|other| other.overlaps(fix)) && choose(fix, &content) {
+                chosen.push(fix);
+            }
+        }
+        if chosen.is_empty() {
+            return Ok(());
+        }
+
+        let edited = edit(&content, &chosen);
+        // recorded first, so that a partly written file is restored too
+        originals.files.push((path.clone(), content));
+        applied.extend(chosen);
+        fs::write(&path, edited)
+    });
+    match result {
+        Ok(()) => Ok((applied, originals)),
+        Err(error) => {
+            originals.restore().ok();
+            Err(error)
+        },
+    }
+}
+
+/// Asks the user whether to apply each fix, for `cargo clippy --fix --interactive`.
+#[derive(Default)]
+struct Prompt<'a> {
+    /// The lints whose fixes are all accepted.
+    accepted: Vec<&'a str>,
+    quit: bool,
+}
+
+impl<'a> Prompt<'a> {
+    /// Shows the diff of `fix` to the file with the content `content`, and asks whether to apply
+    /// it.
+    fn ask(&mut self, fix: &Fix<'a>, content: &str) -> bool {
+        if self.quit {
+            return false;
+        }
+        if self.accepted.contains(&fix.lint) {
+            return true;
+        }
+
+        let color = atty::is(atty::Stream::Stderr);
+        eprintln!();
+        eprintln!(
+            "{}",
+            diff::paint(
+                diff::BOLD,
+                &format!("clippy::{}: {}", fix.lint, fix.diag.message),
+                color
+            )
+        );
+        if let Some(what_it_does) = explain::what_it_does(fix.lint) {
+            eprintln!("{}", what_it_does);
+        }
+        eprintln!("help: {}", fix.message);
+        let edits: Vec<_> = fix.edits.iter().collect();
+        eprint!("{}", diff::unified(fix.file, content, &edits, color));
+
+        loop {
+            eprint!(
+                "Apply this suggestion? [y]es, [n]o, [a]ll `clippy::{}` suggestions, [q]uit: ",
+                fix.lint
+            );
+            let mut answer = String::new();
+            if matches!(io::stdin().read_line(&mut answer), Ok(0) | Err(_)) {
+                self.quit = true;
+                return false;
+            }
+            match answer.trim() {
+                "y" | "yes" => return true,
+                "n" | "no" => return false,
+                "a" | "all" => {
+                    self.accepted.push(fix.lint);
+                    return true;
+                },
+                "q" | "quit" => {
+                    self.quit = true;
+                    return false;
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+/// Checks that the files of the git repository in the current directory, if any, have no
+/// uncommitted changes which `--fix` could mix with its own. The changes added to the index are
+/// accepted with `allow_staged`.
+pub fn check_version_control(allow_staged: bool) -> Result<(), String> {
+    let output = match Command::new("git").args(&["status", "--porcelain"]).output() {
+        Ok(output) if output.status.success() => output,
+        // not in a git repository
+        _ => return Ok(()),
+    };
+    let mut changed = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        // `XY <path>`, with the status of the index in `X` and of the working tree in `Y`
+        let (index, worktree, path) = match (line.get(..1), line.get(1..2), line.get(3..)) {
+            (Some(index), Some(worktree), Some(path)) => (index, worktree, path),
+            _ => continue,
+        };
+        if worktree != " " {
+            changed.push(format!("  * {} (dirty)", path));
+        } else if index != " " && !allow_staged {
+            changed.push(format!("  * {} (staged)", path));
+        }
+    }
+    if changed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "the working directory of this package has uncommitted changes, and `cargo clippy --fix` can \
+             potentially perform destructive changes; if you'd like to suppress this error pass `--allow-dirty`, \
+             `--allow-staged`, or commit the changes to these files:\n\n{}\n",
+            changed.join("\n")
+        ))
+    }
+}
+
+/// Runs `cargo`, which outputs JSON, and reads the compiler messages.
+fn check(cargo: &impl Fn() -> Command) -> Result<(Vec<Diagnostic>, ExitStatus), i32> {
+    let mut child = cargo().spawn().expect("could not run cargo");
+    let output = BufReader::new(child.stdout.take().expect("cargo's output is piped"));
+    let diagnostics = diagnostic::read_all(output).map_err(|error| {
+        eprintln!("error: could not read cargo's output: {}", error);
+        child.kill().ok();
+        1
+    })?;
+    let status = child.wait().expect("failed to wait for cargo?");
+    Ok((diagnostics, status))
+}
+
+/// Prints how many fixes were applied for each lint.
+pub fn print_summary(applied: &[&Fix<'_>]) {
+    let mut lints = BTreeMap::<&str, usize>::new();
+    let mut files = Vec::new();
+    for fix in applied {
+        *lints.entry(fix.lint).or_default() += 1;
+        if !files.contains(&fix.file) {
+            files.push(fix.file);
+        }
+    }
+    eprintln!(
+        "note: applied {} Clippy suggestion(s) to {} file(s)",
+        applied.len(),
+        files.len()
+    );
+    for (lint, count) in lints {
+        eprintln!("{:>8} clippy::{}", count, lint);
+    }
+}
+
+/// Checks the code with `cargo`, which outputs JSON, applies the suggestions, asking the user first
+/// if interactive, and checks the code again, restoring the original files if it doesn't compile
+/// anymore, unless `options.broken_code` is set.
+pub fn run(cargo: impl Fn() -> Command, options: &Options) -> Result<(), i32> {
+    let (diagnostics, status) = check(&cargo)?;
+    if !options.broken_code && diagnostics.iter().any(Diagnostic::is_compile_error) {
+        diagnostic::print(&diagnostics);
+        eprintln!("error: could not apply the Clippy suggestions because the code doesn't compile");
+        return Err(status.code().unwrap_or(1));
+    }
+
+    let fixes: Vec<_> = diagnostics.iter().filter_map(|diag| options.fix(diag)).collect();
+    let mut prompt = Prompt::default();
+    let (applied, originals) =
+        apply(&fixes, |fix, content| !options.interactive || prompt.ask(fix, content)).map_err(|error| {
+            eprintln!("error: could not apply the Clippy suggestions: {}", error);
+            1
+        })?;
+    if applied.is_empty() {
+        diagnostic::print(&diagnostics);
+        eprintln!("note: no Clippy suggestions to apply");
+        return exit_code(status);
+    }
+
+    let (diagnostics, status) = check(&cargo)?;
+    if !options.broken_code && diagnostics.iter().any(Diagnostic::is_compile_error) {
+        let errors: Vec<_> = diagnostics.into_iter().filter(Diagnostic::is_compile_error).collect();
+        diagnostic::print(&errors);
+        if let Err(error) = originals.restore() {
+            eprintln!("error: could not restore the original files: {}", error);
+            return Err(1);
+        }
+        eprintln!("error: the code doesn't compile with the Clippy suggestions, the original files were restored");
+        return Err(1);
+    }
+
+    diagnostic::print(&diagnostics);
+    print_summary(&applied);
+    exit_code(status)
+}
+
+/// The location of the fix in its file, for messages.
+fn location(fix: &Fix<'_>) -> String {
+    fix.diag.primary_span().map_or_else(
+        || fix.file.to_string(),
+        |span| format!("{}:{}:{}", span.file_name, span.line_start, span.column_start),
+    )
+}
+
+/// The path of `file` relative to the root of the git repository, as `git apply` expects it, or
+/// relative to the workspace root outside of a repository.
+fn patch_path(file: &str, path: &Path) -> String {
+    let root = Command::new("git")
+        .args(&["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            PathBuf::from(String::from_utf8_lossy(&output.stdout).trim())
+                .canonicalize()
+                .ok()
+        });
+    match (root, path.canonicalize()) {
+        (Some(root), Ok(path)) => path
+            .strip_prefix(root)
+            .map_or_else(|_| file.to_string(), |path| path.to_string_lossy().replace('\\', "/")),
+        _ => file.to_string(),
+    }
+}
+
+/// Leaves out the fixes which overlap another one, reporting them.
+fn without_conflicts<'f, 'a>(fixes: Vec<&'f Fix<'a>>) -> Vec<&'f Fix<'a>> {
+    let mut conflicting = vec![false; fixes.len()];
+    for (i, fix) in fixes.iter().enumerate() {
+        for (j, other) in fixes.iter().enumerate().skip(i + 1) {
+            if fix.overlaps(other) {
+                eprintln!(
+                    "warning: left out the conflicting suggestions of `clippy::{}` at {} and `clippy::{}` at {}",
+                    fix.lint,
+                    location(fix),
+                    other.lint,
+                    location(other)
+                );
+                conflicting[i] = true;
+                conflicting[j] = true;
+            }
+        }
+    }
+    fixes
+        .into_iter()
+        .zip(conflicting)
+        .filter(|(_, conflicting)| !conflicting)
+        .map(|(fix, _)| fix)
+        .collect()
+}
+
+/// Renders `fixes` as a patch. The fixes which overlap another one are left out and reported.
+///
+/// Returns the patch and the number of fixes in it.
+fn patch(fixes: &[Fix<'_>]) -> io::Result<(String, usize)> {
+    let mut by_file = BTreeMap::<&str, Vec<&Fix<'_>>>::new();
+    for fix in fixes {
+        by_file.entry(fix.file).or_default().push(fix);
+    }
+
+    let mut rendered = String::new();
+    let mut count = 0;
+    for (file, fixes) in by_file {
+        let path = match diagnostic::locate(file) {
+            Some(path) => path,
+            None => continue,
+        };
+        let content = fs::read_to_string(&path)?;
+        let fixes: Vec<_> = fixes.into_iter().filter(|fix| fix.fits(&content)).collect();
+
+        let fixes = without_conflicts(fixes);
+        count += fixes.len();
+        let edits: Vec<&Edit> = fixes.iter().flat_map(|fix| &fix.edits).collect();
+        if edits.is_empty() {
+            continue;
+        }
+        let file = patch_path(file, &path);
+        rendered.push_str(&format!("diff --git a/{} b/{}\n", file, file));
+        rendered.push_str(&diff::unified(&file, &content, &edits, false));
+    }
+    Ok((rendered, count))
+}
+
+/// Checks the code with `cargo`, which outputs JSON, and writes its suggestions to a patch at
+/// `path`, for `cargo clippy --emit-patch <PATH>`. The source files are left untouched.
+pub fn emit_patch(cargo: impl Fn() -> Command, options: &Options, path: &Path) -> Result<(), i32> {
+    let (diagnostics, status) = check(&cargo)?;
+    diagnostic::print(&diagnostics);
+
+    let fixes: Vec<_> = diagnostics.iter().filter_map(|diag| options.fix(diag)).collect();
+    let written = patch(&fixes).and_then(|(patch, count)| fs::write(path, patch).map(|()| count));
+    match written {
+        Ok(count) => eprintln!(
+            "note: wrote {} Clippy suggestion(s) to the patch `{}`",
+            count,
+            path.display()
+        ),
+        Err(error) => {
+            eprintln!("error: could not write the patch `{}`: {}", path.display(), error);
+            return Err(1);
+        },
+    }
+    exit_code(status)
+}
+
+fn exit_code(status: ExitStatus) -> Result<(), i32> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(status.code().unwrap_or(-1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit, without_conflicts, Applicability, Edit, Options};
+    use crate::diagnostic::Diagnostic;
+
+    /// A `needless_return` warning with a suggestion of the given applicability.
+    fn diagnostic(applicability: &str) -> Diagnostic {
+        let span = |replacement: &str| {
+            format!(
+                r#"{{
+                    "file_name": "src/main.rs", "byte_start": 20, "byte_end": 29,
+                    "line_start": 2, "line_end": 2, "column_start": 5, "column_end": 14,
+                    "is_primary": true, "label": null,
+                    "suggested_replacement": {}, "suggestion_applicability": {}
+                }}"#,
+                replacement, applicability
+            )
+        };
+        serde_json::from_str(&format!(
+            r#"{{
+                "message": "unneeded `return` statement",
+                "code": {{"code": "clippy::needless_return"}},
+                "level": "warning",
+                "spans": [{}],
+                "children": [{{
+                    "message": "remove `return`", "code": null, "level": "help",
+                    "spans": [{}], "children": [], "rendered": null
+                }}],
+                "rendered": null
+            }}"#,
+            span("null"),
+            span(r#""1""#)
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn compile_errors() {
+        let diag = |message: &str, code: &str| -> Diagnostic {
+            serde_json::from_str(&format!(
+                r#"{{"message": "{}", "code": {}, "level": "error", "spans": [], "children": [], "rendered": null}}"#,
+                message, code
+            ))
+            .unwrap()
+        };
+        assert!(diag("mismatched types", r#"{"code": "E0308"}"#).is_compile_error());
+        assert!(diag("expected one of `.`, `;`, `?`, or an operator, found `}`", "null").is_compile_error());
+        // the lints denied with `-D warnings`
+        assert!(!diag("unused variable: `x`", r#"{"code": "unused_variables"}"#).is_compile_error());
+        assert!(!diag("unneeded `return` statement", r#"{"code": "clippy::needless_return"}"#).is_compile_error());
+        assert!(!diag("aborting due to 2 previous errors", "null").is_compile_error());
+    }
+
+    #[test]
+    fn applicability() {
+        let options = |applicability, lints| Options {
+            applicability,
+            lints,
+            interactive: false,
+            broken_code: false,
+        };
+        let machine_applicable = diagnostic(r#""MachineApplicable""#);
+        let maybe_incorrect = diagnostic(r#""MaybeIncorrect""#);
+
+        let fix = options(Applicability::MachineApplicable, vec![])
+            .fix(&machine_applicable)
+            .unwrap();
+        assert_eq!(fix.lint, "needless_return");
+        assert_eq!(
+            fix.edits,
+            [Edit {
+                start: 20,
+                end: 29,
+                replacement: "1".into()
+            }]
+        );
+        assert!(options(Applicability::MachineApplicable, vec![])
+            .fix(&maybe_incorrect)
+            .is_none());
+        assert!(options(Applicability::MaybeIncorrect, vec![])
+            .fix(&maybe_incorrect)
+            .is_some());
+        assert!(options(Applicability::MaybeIncorrect, vec!["box_vec"])
+            .fix(&maybe_incorrect)
+            .is_none());
+    }
+
+    #[test]
+    fn edits() {
+        let diag = diagnostic(r#""MachineApplicable""#);
+        let options = Options {
+            applicability: Applicability::MachineApplicable,
+            lints: Vec::new(),
+            interactive: false,
+            broken_code: false,
+        };
+        let mut fix = options.fix(&diag).unwrap();
+        fix.edits = vec![
+            Edit {
+                start: 8,
+                end: 13,
+                replacement: "a".into(),
+            },
+            Edit {
+                start: 0,
+                end: 0,
+                replacement: "// ".into(),
+            },
+        ];
+        assert_eq!(edit("let x = 1 + 1;", &[&fix]), "// let x = a;");
+    }
+
+    #[test]
+    fn conflicts() {
+        let diag = diagnostic(r#""MachineApplicable""#);
+        let options = Options {
+            applicability: Applicability::MachineApplicable,
+            lints: Vec::new(),
+            interactive: false,
+            broken_code: false,
+        };
+        let fix = |start, end| {
+            let mut fix = options.fix(&diag).unwrap();
+            fix.edits[0].start = start;
+            fix.edits[0].end = end;
+            fix
+        };
+        let fixes = [fix(0, 4), fix(2, 6), fix(6, 8), fix(10, 10), fix(10, 12)];
+        let kept: Vec<_> = without_conflicts(fixes.iter().collect())
+            .iter()
+            .map(|fix| fix.edits[0].start..fix.edits[0].end)
+            .collect();
+        assert_eq!(kept, [6..8]);
+    }
+}
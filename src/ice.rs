@@ -0,0 +1,292 @@
+//! Writes a bundle reproducing a crash of Clippy, so that it can be attached to a bug report.
+//!
+//! What is needed to run the compiler again is recorded while it runs: its arguments, the
+//! effective configuration, and the configuration and source files it reads, including the ones
+//! included with `include_str!` and `include_bytes!`. When it crashes, a directory is written with
+//! these, the version of the toolchain and the full backtrace.
+
+use rustc_data_structures::sync::Lrc;
+use rustc_span::source_map::{FileLoader, RealFileLoader, SourceMap};
+use rustc_span::FileName;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::lazy::SyncLazy;
+use std::panic::PanicInfo;
+use std::path::{Component, Path, PathBuf};
+use std::process;
+use std::sync::{Mutex, TryLockError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The environment variables written to the bundle. Only the ones known to affect the compilation
+/// are, as the environment also has credentials, like `CARGO_REGISTRY_TOKEN`.
+const ENV_VARS: [&str; 15] = [
+    "CARGO_CRATE_NAME",
+    "CARGO_MANIFEST_DIR",
+    "CARGO_PRIMARY_PACKAGE",
+    "CARGO_TARGET_DIR",
+    "CLIPPY_ARGS",
+    "CLIPPY_CONF_DIR",
+    "CLIPPY_DISABLE_DOCS_LINKS",
+    "OUT_DIR",
+    "RUSTC",
+    "RUSTC_WORKSPACE_WRAPPER",
+    "RUSTC_WRAPPER",
+    "RUSTDOCFLAGS",
+    "RUSTFLAGS",
+    "RUSTUP_TOOLCHAIN",
+    "RUST_BACKTRACE",
+];
+
+/// The prefixes of the other environment variables written to the bundle, which cargo sets from
+/// the manifest and the target.
+const ENV_PREFIXES: [&str; 3] = ["CARGO_CFG_", "CARGO_FEATURE_", "CARGO_PKG_"];
+
+/// What is known about the compilation so far.
+#[derive(Default)]
+struct Recorded {
+    /// The arguments the compiler was run with.
+    args: Vec<String>,
+    /// The effective Clippy configuration, once it is read.
+    conf: Option<String>,
+    /// The configuration files and the source files read by the compiler, in the order they were
+    /// read.
+    files: Vec<PathBuf>,
+}
+
+static RECORDED: SyncLazy<Mutex<Recorded>> = SyncLazy::new(Mutex::default);
+
+thread_local! {
+    /// The source map of the compilation running on this thread, which the crashes happen on.
+    static SOURCE_MAP: RefCell<Option<Lrc<SourceMap>>> = RefCell::new(None);
+}
+
+fn record(f: impl FnOnce(&mut Recorded)) {
+    if let Ok(mut recorded) = RECORDED.lock() {
+        f(&mut recorded);
+    }
+}
+
+/// Records the arguments the compiler is run with.
+pub fn record_args(args: &[String]) {
+    record(|recorded| recorded.args = args.to_vec());
+}
+
+/// Records the effective configuration and the files it was read from.
+pub fn record_conf(conf: &clippy_lints::Conf) {
+    record(|recorded| {
+        recorded.conf = Some(format!("{:#?}", conf));
+        for source in conf.sources.values() {
+            for file in &source.files {
+                if !recorded.files.contains(file) {
+                    recorded.files.push(file.clone());
+                }
+            }
+        }
+    });
+}
+
+/// Records the source map of the compilation. The files included with `include_str!` and
+/// `include_bytes!` are added to it without being read by the file loader.
+pub fn record_source_map(source_map: Lrc<SourceMap>) {
+    SOURCE_MAP.with(|recorded| *recorded.borrow_mut() = Some(source_map));
+}
+
+/// The local source files in the source map of the compilation running on this thread.
+fn source_map_files() -> Vec<PathBuf> {
+    SOURCE_MAP
+        .try_with(|source_map| match &*source_map.borrow() {
+            Some(source_map) => source_map
+                .files()
+                .iter()
+                .filter(|file| !file.is_imported())
+                .filter_map(|file| match &file.name {
+                    FileName::Real(name) => Some(name.local_path().to_path_buf()),
+                    _ => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        })
+        .unwrap_or_default()
+}
+
+/// A file loader recording the files the compiler reads, like the modules of the crate.
+pub struct RecordingFileLoader(pub Box<dyn FileLoader + Send + Sync>);
+
+impl RecordingFileLoader {
+    #[must_use]
+    pub fn new(loader: Option<Box<dyn FileLoader + Send + Sync>>) -> Self {
+        Self(loader.unwrap_or_else(|| Box::new(RealFileLoader)))
+    }
+}
+
+impl FileLoader for RecordingFileLoader {
+    fn file_exists(&self, path: &Path) -> bool {
+        self.0.file_exists(path)
+    }
+
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        let contents = self.0.read_file(path)?;
+        record(|recorded| {
+            if !recorded.files.iter().any(|file| file == path) {
+                recorded.files.push(path.to_path_buf());
+            }
+        });
+        Ok(contents)
+    }
+}
+
+/// Writes the bundle of the crash described by `info`, in `CLIPPY_ICE_DIR` or the temporary
+/// directory, and returns its path.
+pub fn write_bundle(info: &PanicInfo<'_>, clippy_version: &str) -> io::Result<PathBuf> {
+    let backtrace = Backtrace::force_capture();
+    // the crash may have happened while recording
+    let recorded = match RECORDED.try_lock() {
+        Ok(recorded) => recorded,
+        Err(TryLockError::Poisoned(error)) => error.into_inner(),
+        Err(TryLockError::WouldBlock) => {
+            return Err(io::Error::new(io::ErrorKind::Other, "the recorded state is locked"));
+        },
+    };
+
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let dir = env::var_os("CLIPPY_ICE_DIR")
+        .map_or_else(env::temp_dir, PathBuf::from)
+        .join(format!("clippy-ice-{}-{}", secs, process::id()));
+    fs::create_dir_all(&dir)?;
+
+    let mut args = String::new();
+    for arg in &recorded.args {
+        writeln!(args, "{}", arg).unwrap();
+    }
+    fs::write(dir.join("args.txt"), args)?;
+
+    let cwd = env::current_dir()?;
+    fs::write(dir.join("env.txt"), environment(&cwd, env::vars()))?;
+
+    let version = format!(
+        "clippy: {}\nrustc: {}\ncommit-hash: {}\nhost: {}\n",
+        clippy_version,
+        rustc_interface::util::version_str().unwrap_or("unknown"),
+        rustc_interface::util::commit_hash_str().unwrap_or("unknown"),
+        rustc_session::config::host_triple(),
+    );
+    fs::write(dir.join("version.txt"), version)?;
+
+    if let Some(conf) = &recorded.conf {
+        fs::write(dir.join("conf.txt"), conf)?;
+    }
+
+    fs::write(dir.join("backtrace.txt"), format!("{}\n\n{}", info, backtrace))?;
+
+    let mut files = recorded.files.clone();
+    for file in source_map_files() {
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
+    // the files which can't be copied anymore are left out
+    for file in &files {
+        let copy = dir.join("files").join(bundle_path(&cwd, file));
+        if let Some(parent) = copy.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(file, copy).ok();
+    }
+
+    Ok(dir)
+}
+
+/// The content of `env.txt`: the working directory `cwd` and the variables of `vars` which are
+/// written to the bundle.
+fn environment(cwd: &Path, vars: impl Iterator<Item = (String, String)>) -> String {
+    let mut vars: Vec<_> = vars
+        .filter(|(name, _)| {
+            ENV_VARS.contains(&name.as_str()) || ENV_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+        })
+        .collect();
+    vars.sort();
+    let mut environment = format!("working directory: {}\n\n", cwd.display());
+    for (name, value) in vars {
+        writeln!(environment, "{}={}", name, value).unwrap();
+    }
+    environment
+}
+
+/// The path of a file in the `files` directory of the bundle: relative to the working directory
+/// `cwd` if it is in it, so that the compiler can be run again from the `files` directory, or
+/// under `root` otherwise.
+fn bundle_path(cwd: &Path, path: &Path) -> PathBuf {
+    let mut absolute = PathBuf::new();
+    for component in cwd.join(path).components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                absolute.pop();
+            },
+            component => absolute.push(component),
+        }
+    }
+    match absolute.strip_prefix(cwd) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => Path::new("root").join(
+            absolute
+                .components()
+                .filter(|component| matches!(component, Component::Normal(_)))
+                .collect::<PathBuf>(),
+        ),
+    }
+}
+
+#[test]
+fn test_bundle_path() {
+    let cwd = Path::new("/work/crate");
+
+    assert_eq!(bundle_path(cwd, Path::new("src/lib.rs")), Path::new("src/lib.rs"));
+    assert_eq!(bundle_path(cwd, Path::new("./src/lib.rs")), Path::new("src/lib.rs"));
+    assert_eq!(
+        bundle_path(cwd, Path::new("/work/crate/src/a.rs")),
+        Path::new("src/a.rs")
+    );
+    assert_eq!(bundle_path(cwd, Path::new("src/../build.rs")), Path::new("build.rs"));
+    assert_eq!(
+        bundle_path(cwd, Path::new("../shared/mod.rs")),
+        Path::new("root/work/shared/mod.rs")
+    );
+    assert_eq!(
+        bundle_path(cwd, Path::new("/etc/clippy.toml")),
+        Path::new("root/etc/clippy.toml")
+    );
+}
+
+#[test]
+fn test_environment() {
+    let vars = [
+        ("RUSTFLAGS", "-Dwarnings"),
+        ("CARGO_PKG_NAME", "foo"),
+        ("CLIPPY_ARGS", "-W__CLIPPY_HACKERY__clippy::pedantic__CLIPPY_HACKERY__"),
+        ("CARGO_REGISTRY_TOKEN", "secret"),
+        ("CARGO_REGISTRIES_MY_REGISTRY_TOKEN", "secret"),
+        ("CARGO_HOME", "/home/user/.cargo"),
+        ("RUSTUP_HOME", "/home/user/.rustup"),
+        ("HOME", "/home/user"),
+    ];
+    let vars = vars
+        .iter()
+        .map(|(name, value)| ((*name).to_string(), (*value).to_string()));
+    assert_eq!(
+        environment(Path::new("/work/crate"), vars),
+        "\
+working directory: /work/crate
+
+CARGO_PKG_NAME=foo
+CLIPPY_ARGS=-W__CLIPPY_HACKERY__clippy::pedantic__CLIPPY_HACKERY__
+RUSTFLAGS=-Dwarnings
+"
+    );
+}
@@ -5,8 +5,21 @@
 use rustc_tools_util::VersionInfo;
 use std::env;
 use std::ffi::OsString;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+
+mod baseline;
+mod diagnostic;
+mod diff;
+mod explain;
+mod fix;
+mod report;
+
+use baseline::Baseline;
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -16,6 +29,34 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+        --explain <LINT>     Print the documentation of a lint and exit
+        --fix                Apply the suggestions of the lints, then check that the code still
+                             compiles and restore the original files otherwise
+        --allow-dirty        With `--fix`, fix code even if the working directory has changes
+        --allow-staged       With `--fix`, fix code even if the working directory has staged changes
+        --broken-code        With `--fix`, fix code even if it already has compiler errors, and keep
+                             the suggestions if the code doesn't compile with them
+        --emit-patch <PATH>  Write the suggestions of the lints to a patch instead of applying them
+        --interactive        With `--fix`, show each suggestion and ask whether to apply it
+        --fix-lints <LINTS>  Only apply the suggestions of the given comma-separated lints
+        --fix-applicability <APPLICABILITY>
+                             Apply the suggestions which are `machine-applicable` (default) or
+                             also the ones which are `maybe-incorrect` (default with
+                             `--interactive`)
+        --clippy-profile <NAME>
+                             Apply the lint levels of the `[profile.<NAME>]` table of clippy.toml
+        --report-format <FORMAT>
+                             Write a report of the Clippy diagnostics in the given format:
+                             sarif, codeclimate, junit or checkstyle
+        --report-path <PATH> Write the report to the given file instead of stdout
+        --write-baseline <PATH>
+                             Record the current Clippy findings in the given baseline file
+        --baseline <PATH>    Only report the Clippy findings missing from the given baseline file
+        --changed-since <REV>
+                             Only report the Clippy findings on the lines changed since the given
+                             git revision
+        --changed-items      With `--changed-since`, report the Clippy findings in the whole items
+                             containing changed lines
 
 Other options are the same as `cargo check`.
 
@@ -53,45 +94,210 @@ pub fn main() {
         return;
     }
 
+    match explain_arg(env::args()) {
+        Some(Ok(lint)) => {
+            if let Err(code) = explain::explain(&lint) {
+                process::exit(code);
+            }
+            return;
+        },
+        Some(Err(error)) => {
+            eprintln!("error: {}\n\nUsage:\n    cargo clippy --explain <LINT>", error);
+            process::exit(1);
+        },
+        None => {},
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
 }
 
+/// The lint passed to `--explain <LINT>` or `--explain=<LINT>`, if any, or an error if the lint is
+/// missing.
+fn explain_arg<I>(mut args: I) -> Option<Result<String, String>>
+where
+    I: Iterator<Item = String>,
+{
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        } else if arg == "--explain" || arg.starts_with("--explain=") {
+            let lint = match arg.strip_prefix("--explain=") {
+                Some(lint) => Some(lint.to_string()),
+                None => args.next(),
+            };
+            return Some(match lint {
+                Some(lint) if !lint.is_empty() && !lint.starts_with('-') => Ok(lint),
+                _ => Err("`--explain` requires a lint name".to_string()),
+            });
+        }
+    }
+    None
+}
+
+/// The options of `cargo clippy` taking a value, given as `--option value` or `--option=value`.
+const VALUE_OPTIONS: [&str; 9] = [
+    "--clippy-profile",
+    "--emit-patch",
+    "--fix-lints",
+    "--fix-applicability",
+    "--report-format",
+    "--report-path",
+    "--baseline",
+    "--write-baseline",
+    "--changed-since",
+];
+
+/// If `arg` is one of the [`VALUE_OPTIONS`], returns it along with its value, or an error if the
+/// value is missing.
+fn value_option<I>(arg: &str, args: &mut I) -> Option<Result<(&'static str, String), String>>
+where
+    I: Iterator<Item = String>,
+{
+    let name = VALUE_OPTIONS
+        .iter()
+        .find(|name| arg == **name || arg.strip_prefix(**name).map_or(false, |rest| rest.starts_with('=')))?;
+    let value = match arg.strip_prefix(name).and_then(|rest| rest.strip_prefix('=')) {
+        Some(value) => Some(value.to_string()),
+        None => args.next(),
+    };
+    Some(match value {
+        Some(value) if !value.is_empty() && !value.starts_with('-') => Ok((name, value)),
+        _ => Err(format!("`{}` requires a value", name)),
+    })
+}
+
+/// What to do with the Clippy diagnostics, which are then read from cargo's JSON output.
+#[derive(Default)]
+struct Outputs {
+    report_format: Option<String>,
+    report_path: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    write_baseline: Option<PathBuf>,
+}
+
+impl Outputs {
+    fn is_empty(&self) -> bool {
+        self.report_format.is_none() && self.baseline.is_none() && self.write_baseline.is_none()
+    }
+
+    /// Checks that the options which only apply to another one are given with it.
+    fn check(&self) -> Result<(), &'static str> {
+        if self.report_path.is_some() && self.report_format.is_none() {
+            return Err("`--report-path` requires `--report-format`");
+        }
+        Ok(())
+    }
+}
+
+/// Which uncommitted changes `--fix` may mix with its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum AllowedChanges {
+    None,
+    /// `--allow-staged`
+    Staged,
+    /// `--allow-dirty`
+    Dirty,
+}
+
+impl Default for AllowedChanges {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// The options of `--fix` and `--emit-patch`.
+#[derive(Default)]
+struct FixOptions {
+    fix: bool,
+    interactive: bool,
+    allowed_changes: AllowedChanges,
+    broken_code: bool,
+    emit_patch: Option<PathBuf>,
+    lints: Vec<String>,
+    applicability: Option<String>,
+}
+
+impl FixOptions {
+    /// Whether any of the options which require `--fix` is given.
+    fn requires_fix(&self) -> bool {
+        self.interactive || self.allowed_changes != AllowedChanges::None || self.broken_code
+    }
+}
+
 struct ClippyCmd {
-    cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    outputs: Outputs,
+    /// The git revision passed to `--changed-since`, resolved to a commit before running cargo.
+    changed_since: Option<String>,
+    changed_items: bool,
+    fix: FixOptions,
 }
 
 impl ClippyCmd {
-    fn new<I>(mut old_args: I) -> Self
+    fn new<I>(mut old_args: I) -> Result<Self, String>
     where
         I: Iterator<Item = String>,
     {
-        let mut cargo_subcommand = "check";
-        let mut unstable_options = false;
+        let mut fix = FixOptions::default();
+        let mut profile = None;
+        let mut outputs = Outputs::default();
+        let mut changed_since = None;
+        let mut changed_items = false;
         let mut args = vec![];
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
+            if let Some(option) = value_option(&arg, &mut old_args) {
+                let (name, value) = option?;
+                match name {
+                    "--clippy-profile" => profile = Some(value),
+                    "--emit-patch" => fix.emit_patch = Some(value.into()),
+                    "--fix-lints" => fix.lints.extend(value.split(',').map(|lint| lint.trim().to_string())),
+                    "--fix-applicability" => fix.applicability = Some(value),
+                    "--report-format" => outputs.report_format = Some(value),
+                    "--report-path" => outputs.report_path = Some(value.into()),
+                    "--baseline" => outputs.baseline = Some(value.into()),
+                    "--write-baseline" => outputs.write_baseline = Some(value.into()),
+                    "--changed-since" => changed_since = Some(value),
+                    _ => unreachable!(),
+                }
+                continue;
+            }
+
             match arg.as_str() {
                 "--fix" => {
-                    cargo_subcommand = "fix";
+                    fix.fix = true;
+                    continue;
+                },
+                "--allow-dirty" => {
+                    fix.allowed_changes = AllowedChanges::Dirty;
+                    continue;
+                },
+                "--allow-staged" => {
+                    fix.allowed_changes = fix.allowed_changes.max(AllowedChanges::Staged);
+                    continue;
+                },
+                "--broken-code" => {
+                    fix.broken_code = true;
+                    continue;
+                },
+                "--interactive" => {
+                    fix.interactive = true;
+                    continue;
+                },
+                "--changed-items" => {
+                    changed_items = true;
                     continue;
                 },
                 "--" => break,
-                // Cover -Zunstable-options and -Z unstable-options
-                s if s.ends_with("unstable-options") => unstable_options = true,
                 _ => {},
             }
 
             args.push(arg);
         }
 
-        if cargo_subcommand == "fix" && !unstable_options {
-            panic!("Usage of `--fix` requires `-Z unstable-options`");
-        }
-
         // Run the dogfood tests directly on nightly cargo. This is required due
         // to a bug in rustup.rs when running cargo on custom toolchains. See issue #3118.
         if env::var_os("CLIPPY_DOGFOOD").is_some() && cfg!(windows) {
@@ -99,15 +305,24 @@ impl ClippyCmd {
         }
 
         let mut clippy_args: Vec<String> = old_args.collect();
-        if cargo_subcommand == "fix" && !clippy_args.iter().any(|arg| arg == "--no-deps") {
+        if (fix.fix || fix.emit_patch.is_some()) && !clippy_args.iter().any(|arg| arg == "--no-deps") {
             clippy_args.push("--no-deps".into());
         }
+        if let Some(profile) = profile {
+            clippy_args.push(format!("--clippy-profile={}", profile));
+        }
+        if changed_items {
+            clippy_args.push("--changed-items".into());
+        }
 
-        ClippyCmd {
-            cargo_subcommand,
+        Ok(ClippyCmd {
             args,
             clippy_args,
-        }
+            outputs,
+            changed_since,
+            changed_items,
+            fix,
+        })
     }
 
     fn path() -> PathBuf {
@@ -138,7 +353,7 @@ impl ClippyCmd {
             .map(|p| ("CARGO_TARGET_DIR", p))
     }
 
-    fn into_std_cmd(self) -> Command {
+    fn to_std_cmd(&self) -> Command {
         let mut cmd = Command::new("cargo");
         let clippy_args: String = self
             .clippy_args
@@ -149,77 +364,481 @@ impl ClippyCmd {
         cmd.env("RUSTC_WORKSPACE_WRAPPER", Self::path())
             .envs(ClippyCmd::target_dir())
             .env("CLIPPY_ARGS", clippy_args)
-            .arg(self.cargo_subcommand)
+            .arg("check")
             .args(&self.args);
 
         cmd
     }
 }
 
+/// The compiler messages at `error` level, which fail the build.
+#[derive(Default)]
+struct Errors {
+    /// The compile errors and the lints at `error` level which are reported.
+    reported: usize,
+    /// The lints at `error` level suppressed by the baseline.
+    suppressed: usize,
+}
+
+/// Reads the diagnostics from cargo's JSON `output`, filters them through the baseline and writes
+/// the baseline and the report.
+fn handle_diagnostics(
+    outputs: &Outputs,
+    formatter: Option<&dyn report::Formatter>,
+    output: impl io::BufRead,
+) -> io::Result<Errors> {
+    let mut baseline = match &outputs.baseline {
+        Some(path) => Some(Baseline::read(path).map_err(|error| context(&error, "read the baseline", path))?),
+        None => None,
+    };
+    let mut fingerprinter = baseline::Fingerprinter::default();
+    let mut fingerprints = Vec::new();
+    let mut suppressed = 0;
+    let mut errors = Errors::default();
+
+    let diagnostics = diagnostic::read(output, |diag| {
+        let error = diag.level.starts_with("error") && !diag.is_summary();
+        if diag.lint().is_none() {
+            errors.reported += usize::from(error);
+            // the counts of rustc's summaries include the findings suppressed by the baseline
+            return baseline.is_none() || !diag.is_summary();
+        }
+        let fingerprint = fingerprinter.fingerprint(diag);
+        let keep = !baseline
+            .as_mut()
+            .map_or(false, |baseline| baseline.suppresses(&fingerprint));
+        suppressed += usize::from(!keep);
+        if error {
+            if keep {
+                errors.reported += 1;
+            } else {
+                errors.suppressed += 1;
+            }
+        }
+        fingerprints.push(fingerprint);
+        keep
+    })?;
+
+    if let Some(baseline) = &baseline {
+        if suppressed > 0 {
+            eprintln!("note: {} Clippy warning(s) suppressed by the baseline", suppressed);
+        }
+        for (fingerprint, count) in baseline.stale() {
+            eprintln!(
+                "note: stale baseline entry, `{}` doesn't fire {} time(s) anymore in `{}` at `{}`: `{}`",
+                fingerprint.lint, count, fingerprint.file, fingerprint.item, fingerprint.snippet
+            );
+        }
+    }
+    if let Some(path) = &outputs.write_baseline {
+        baseline::write(path, &fingerprints).map_err(|error| context(&error, "write the baseline", path))?;
+        eprintln!(
+            "note: recorded {} Clippy finding(s) in the baseline `{}`",
+            fingerprints.len(),
+            path.display()
+        );
+    }
+
+    if let Some(formatter) = formatter {
+        match &outputs.report_path {
+            Some(path) => File::create(path)
+                .and_then(|file| {
+                    let mut file = io::BufWriter::new(file);
+                    formatter.write(&diagnostics, &mut file)?;
+                    file.flush()
+                })
+                .map_err(|error| context(&error, "write the report", path))?,
+            None => formatter.write(&diagnostics, &mut io::stdout().lock())?,
+        }
+    }
+    Ok(errors)
+}
+
+/// Forwards cargo's stderr to ours, except for the errors of cargo about the crates which didn't
+/// compile, which are returned instead. They are only printed if the errors that failed the build
+/// weren't all suppressed by the baseline. Also returns whether cargo reported other errors, like
+/// a failed build script.
+fn forward_stderr(stderr: impl io::Read) -> (Vec<String>, bool) {
+    let mut held = Vec::new();
+    let mut holding = false;
+    let mut failed = false;
+    for line in BufReader::new(stderr).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        holding = line.starts_with("error: could not compile `")
+            || line.starts_with("warning: build failed, waiting for other jobs to finish")
+            || line.starts_with("To learn more, run the command again with --verbose")
+            || (holding && line.is_empty());
+        if holding {
+            held.push(line);
+        } else {
+            failed |= line.starts_with("error");
+            eprintln!("{}", line);
+        }
+    }
+    (held, failed)
+}
+
+/// The options of `cargo clippy --fix` and `--emit-patch` given on the command line, checking the
+/// lint names and the applicability.
+fn fix_options(options: &FixOptions) -> Result<fix::Options, i32> {
+    let applicability = match options.applicability.as_deref() {
+        // the user reviews the suggestions which may be incorrect
+        None if options.interactive => fix::Applicability::MaybeIncorrect,
+        None => fix::Applicability::MachineApplicable,
+        Some(name) => fix::Applicability::from_name(name).ok_or_else(|| {
+            eprintln!(
+                "error: unknown applicability `{}`, expected one of: {}",
+                name,
+                fix::Applicability::NAMES.join(", ")
+            );
+            1
+        })?,
+    };
+    let lints = options
+        .lints
+        .iter()
+        .map(|lint| explain::lint_name(lint).ok_or(1))
+        .collect::<Result<_, _>>()?;
+    Ok(fix::Options {
+        applicability,
+        lints,
+        interactive: options.interactive,
+        broken_code: options.broken_code,
+    })
+}
+
+/// Resolves the git revision `rev` to the hash of its commit, so that cargo checks the crates again
+/// when the revision moves.
+fn resolve_revision(rev: &str) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(&["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", rev)])
+        .output()
+        .map_err(|error| format!("could not run git: {}", error))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(format!("unknown git revision `{}`", rev))
+    }
+}
+
+/// Adds to `error` what couldn't be done with the file at `path`.
+fn context(error: &io::Error, what: &str, path: &Path) -> io::Error {
+    io::Error::new(
+        error.kind(),
+        format!("could not {} `{}`: {}", what, path.display(), error),
+    )
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
-    let cmd = ClippyCmd::new(old_args);
+    let mut cmd = ClippyCmd::new(old_args).map_err(|error| {
+        eprintln!("error: {}\n\nFor more information, try `cargo clippy --help`", error);
+        1
+    })?;
+    match cmd.changed_since.take() {
+        Some(rev) => match resolve_revision(&rev) {
+            Ok(commit) => cmd.clippy_args.push(format!("--changed-since={}", commit)),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                return Err(1);
+            },
+        },
+        None if cmd.changed_items => {
+            eprintln!("error: `--changed-items` requires `--changed-since`");
+            return Err(1);
+        },
+        None => {},
+    }
+    let outputs = mem::take(&mut cmd.outputs);
+    if let Err(error) = outputs.check() {
+        eprintln!("error: {}", error);
+        return Err(1);
+    }
+    let json = !outputs.is_empty();
+
+    if cmd.fix.fix || cmd.fix.emit_patch.is_some() {
+        if json {
+            eprintln!("error: `--fix` and `--emit-patch` can't be used with reports or baselines");
+            return Err(1);
+        }
+        if cmd.fix.fix && cmd.fix.emit_patch.is_some() {
+            eprintln!("error: `--fix` and `--emit-patch` can't be used together");
+            return Err(1);
+        }
+        if !cmd.fix.fix && cmd.fix.requires_fix() {
+            eprintln!("error: `--interactive`, `--allow-dirty`, `--allow-staged` and `--broken-code` require `--fix`");
+            return Err(1);
+        }
+        if cmd.fix.fix && cmd.fix.allowed_changes != AllowedChanges::Dirty {
+            if let Err(error) = fix::check_version_control(cmd.fix.allowed_changes == AllowedChanges::Staged) {
+                eprintln!("error: {}", error);
+                return Err(1);
+            }
+        }
+        let options = fix_options(&cmd.fix)?;
+        let cargo = || {
+            let mut cargo = cmd.to_std_cmd();
+            cargo.arg("--message-format=json").stdout(Stdio::piped());
+            cargo
+        };
+        return match &cmd.fix.emit_patch {
+            Some(path) => fix::emit_patch(cargo, &options, path),
+            None => fix::run(cargo, &options),
+        };
+    } else if cmd.fix.requires_fix() {
+        eprintln!("error: `--interactive`, `--allow-dirty`, `--allow-staged` and `--broken-code` require `--fix`");
+        return Err(1);
+    } else if !cmd.fix.lints.is_empty() || cmd.fix.applicability.is_some() {
+        eprintln!("error: `--fix-lints` and `--fix-applicability` require `--fix` or `--emit-patch`");
+        return Err(1);
+    }
 
-    let mut cmd = cmd.into_std_cmd();
+    let formatter = match outputs
+        .report_format
+        .as_deref()
+        .map(|name| (name, report::formatter(name)))
+    {
+        Some((name, None)) => {
+            eprintln!(
+                "error: unknown report format `{}`, expected one of: {}",
+                name,
+                report::names().join(", ")
+            );
+            return Err(1);
+        },
+        Some((_, formatter)) => formatter,
+        None => None,
+    };
+
+    let mut cmd = cmd.to_std_cmd();
+    if json {
+        // the diagnostics are read from cargo's output
+        cmd.arg("--message-format=json").stdout(Stdio::piped());
+    }
+    if outputs.baseline.is_some() {
+        // the findings suppressed by the baseline may be all that failed the build
+        cmd.stderr(Stdio::piped());
+    }
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let mut child = cmd.spawn().expect("could not run cargo");
+    let stderr = child
+        .stderr
+        .take()
+        .map(|stderr| thread::spawn(move || forward_stderr(stderr)));
+    let mut errors = Errors::default();
+    if json {
+        let output = BufReader::new(child.stdout.take().expect("cargo's output is piped"));
+        match handle_diagnostics(&outputs, formatter, output) {
+            Ok(read) => errors = read,
+            Err(error) => {
+                eprintln!("error: {}", error);
+                child.kill().ok();
+                return Err(1);
+            },
+        }
+    }
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+    let (held, cargo_failed) = stderr.map_or_else(Default::default, |stderr| stderr.join().unwrap_or_default());
 
-    if exit_status.success() {
+    if exit_status.success() || (errors.reported == 0 && errors.suppressed > 0 && !cargo_failed) {
         Ok(())
     } else {
+        for line in held {
+            eprintln!("{}", line);
+        }
         Err(exit_status.code().unwrap_or(-1))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ClippyCmd;
+    use super::{explain_arg, fix, fix_options, AllowedChanges, ClippyCmd};
+    use std::path::Path;
 
     #[test]
-    #[should_panic]
-    fn fix_without_unstable() {
-        let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        ClippyCmd::new(args);
+    fn fix() {
+        let args = "cargo clippy --fix --fix-lints needless_return,clippy::box-vec --fix-applicability=maybe-incorrect"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.fix.fix);
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.fix.lints, ["needless_return", "clippy::box-vec"]);
+        assert_eq!(cmd.fix.applicability.as_deref(), Some("maybe-incorrect"));
+
+        let options = fix_options(&cmd.fix).unwrap();
+        assert_eq!(options.lints, ["needless_return", "box_vec"]);
+        assert_eq!(options.applicability, fix::Applicability::MaybeIncorrect);
+        assert!(!options.interactive);
+
+        let args = "cargo clippy --fix --interactive"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let options = fix_options(&ClippyCmd::new(args).unwrap().fix).unwrap();
+        assert_eq!(options.applicability, fix::Applicability::MaybeIncorrect);
+        assert!(options.interactive);
     }
 
     #[test]
-    fn fix_unstable() {
-        let args = "cargo clippy --fix -Zunstable-options"
+    fn fix_allow_dirty() {
+        let args = "cargo clippy --fix --allow-dirty --allow-staged --broken-code -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        // `--allow-dirty` allows the staged changes too
+        assert_eq!(cmd.fix.allowed_changes, AllowedChanges::Dirty);
+        assert!(cmd.fix.broken_code);
+        // these are not options of `cargo check`
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.clippy_args, ["-D", "warnings", "--no-deps"]);
+        assert!(fix_options(&cmd.fix).unwrap().broken_code);
+
+        let args = "cargo clippy --fix --allow-staged"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
-        assert_eq!("fix", cmd.cargo_subcommand);
-        assert!(cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
+        assert_eq!(
+            ClippyCmd::new(args).unwrap().fix.allowed_changes,
+            AllowedChanges::Staged
+        );
     }
 
     #[test]
-    fn fix_implies_no_deps() {
-        let args = "cargo clippy --fix -Zunstable-options"
+    fn emit_patch() {
+        let args = "cargo clippy --emit-patch=clippy.patch --fix-lints needless_return"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(!cmd.fix.fix);
+        assert_eq!(cmd.fix.emit_patch.as_deref(), Some(Path::new("clippy.patch")));
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.clippy_args, ["--no-deps"]);
+    }
+
+    #[test]
+    fn fix_implies_no_deps() {
+        let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
     }
 
     #[test]
     fn no_deps_not_duplicated_with_fix() {
-        let args = "cargo clippy --fix -Zunstable-options -- --no-deps"
+        let args = "cargo clippy --fix -- --no-deps"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn profile() {
+        let args = "cargo clippy --clippy-profile ci --all-targets -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(cmd.clippy_args, ["-D", "warnings", "--clippy-profile=ci"]);
+
+        let args = "cargo clippy --clippy-profile=strict"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.clippy_args, ["--clippy-profile=strict"]);
+
+        // cargo's build profiles are forwarded to cargo
+        let args = "cargo clippy --profile release"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.args, ["cargo", "clippy", "--profile", "release"]);
+        assert!(cmd.clippy_args.is_empty());
+
+        for args in &[
+            "cargo clippy --clippy-profile",
+            "cargo clippy --clippy-profile= --all-targets",
+            "cargo clippy --clippy-profile -- -D warnings",
+        ] {
+            let args = args.split_whitespace().map(ToString::to_string);
+            assert_eq!(
+                ClippyCmd::new(args).err().as_deref(),
+                Some("`--clippy-profile` requires a value")
+            );
+        }
+    }
+
+    #[test]
+    fn explain() {
+        let args = |args: &str| args.split_whitespace().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            explain_arg(args("cargo clippy --explain needless_collect").into_iter()),
+            Some(Ok("needless_collect".to_string()))
+        );
+        assert_eq!(
+            explain_arg(args("cargo clippy --explain=box_vec").into_iter()),
+            Some(Ok("box_vec".to_string()))
+        );
+        assert_eq!(explain_arg(args("cargo clippy -- --explain foo").into_iter()), None);
+        for missing in &[
+            "cargo clippy --explain",
+            "cargo clippy --explain=",
+            "cargo clippy --explain --all-targets",
+        ] {
+            assert_eq!(
+                explain_arg(args(missing).into_iter()),
+                Some(Err("`--explain` requires a lint name".to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn outputs() {
+        let args = "cargo clippy --baseline=base.json --report-format sarif --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(cmd.outputs.baseline.as_deref(), Some(Path::new("base.json")));
+        assert_eq!(cmd.outputs.report_format.as_deref(), Some("sarif"));
+        assert!(cmd.outputs.write_baseline.is_none());
+        assert!(cmd.outputs.check().is_ok());
+
+        let args = "cargo clippy --report-format=sarif --report-path clippy.sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.outputs.report_path.as_deref(), Some(Path::new("clippy.sarif")));
+        assert!(cmd.outputs.check().is_ok());
+
+        let args = "cargo clippy --report-path clippy.sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.outputs.check(), Err("`--report-path` requires `--report-format`"));
+    }
+
+    #[test]
+    fn changed_since() {
+        let args = "cargo clippy --changed-since origin/master --changed-items -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.changed_since.as_deref(), Some("origin/master"));
+        assert_eq!(cmd.clippy_args, ["-D", "warnings", "--changed-items"]);
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
-        assert_eq!("check", cmd.cargo_subcommand);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(!cmd.fix.fix);
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert!(cmd.clippy_args.is_empty());
     }
 }
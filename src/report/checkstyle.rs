@@ -0,0 +1,48 @@
+//! [Checkstyle](https://checkstyle.org/) XML, read by many code review tools.
+
+use super::{description, xml_escape, Formatter};
+use crate::diagnostic::Diagnostic;
+use std::collections::BTreeMap;
+use std::io;
+
+pub struct Checkstyle;
+
+impl Formatter for Checkstyle {
+    fn name(&self) -> &'static str {
+        "checkstyle"
+    }
+
+    fn write(&self, diagnostics: &[Diagnostic], out: &mut dyn io::Write) -> io::Result<()> {
+        // the diagnostics are grouped by file
+        let mut files = BTreeMap::<_, Vec<_>>::new();
+        for diag in diagnostics {
+            if let (Some(lint), Some(span)) = (diag.lint(), diag.primary_span()) {
+                files.entry(&span.file_name).or_default().push((lint, span, diag));
+            }
+        }
+
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<checkstyle version="4.3">"#)?;
+        for (file, diagnostics) in files {
+            writeln!(out, r#"  <file name="{}">"#, xml_escape(file))?;
+            for (lint, span, diag) in diagnostics {
+                let severity = match diag.level.as_str() {
+                    "warning" => "warning",
+                    "note" | "help" => "info",
+                    _ => "error",
+                };
+                writeln!(
+                    out,
+                    r#"    <error line="{}" column="{}" severity="{}" message="{}" source="clippy::{}"/>"#,
+                    span.line_start,
+                    span.column_start,
+                    severity,
+                    xml_escape(&description(diag)),
+                    lint
+                )?;
+            }
+            writeln!(out, "  </file>")?;
+        }
+        writeln!(out, "</checkstyle>")
+    }
+}
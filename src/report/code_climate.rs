@@ -0,0 +1,102 @@
+//! The [Code Climate](https://github.com/codeclimate/platform/blob/master/spec/analyzers/SPEC.md)
+//! issue format, read by merge request widgets.
+
+use super::{description, Formatter};
+use crate::baseline::Fingerprinter;
+use crate::diagnostic::Diagnostic;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io;
+
+pub struct CodeClimate;
+
+/// The 64-bit FNV-1a hash of `fields`, as hex digits. Unlike the hashers of the standard library,
+/// its value is the same with every Rust release, so the fingerprints are stable.
+fn fingerprint(fields: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for field in fields {
+        // the fields are separated by a byte which can't appear in UTF-8
+        for &byte in field.as_bytes().iter().chain(&[0xff]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// The Code Climate category of a lint group.
+fn category(group: Option<&str>) -> &'static str {
+    match group {
+        Some("correctness") => "Bug Risk",
+        Some("complexity") => "Complexity",
+        Some("perf") => "Performance",
+        _ => "Style",
+    }
+}
+
+impl Formatter for CodeClimate {
+    fn name(&self) -> &'static str {
+        "codeclimate"
+    }
+
+    fn write(&self, diagnostics: &[Diagnostic], out: &mut dyn io::Write) -> io::Result<()> {
+        let mut fingerprinter = Fingerprinter::default();
+        let mut occurrences = BTreeMap::new();
+        let issues: Vec<Value> = diagnostics
+            .iter()
+            .filter_map(|diag| {
+                let lint = diag.lint()?;
+                let span = diag.primary_span()?;
+
+                // identifies the issue across runs, so it doesn't depend on its position, like the
+                // baseline fingerprint, but it must be unique as the issues with the same
+                // fingerprint are merged, so the identical findings are numbered
+                let found = fingerprinter.fingerprint(diag);
+                let occurrence = occurrences.entry(found.clone()).or_insert(0_usize);
+                *occurrence += 1;
+                let fingerprint = fingerprint(&[
+                    &found.lint,
+                    &found.file,
+                    &found.item,
+                    &found.snippet,
+                    &occurrence.to_string(),
+                ]);
+
+                Some(json!({
+                    "type": "issue",
+                    "check_name": format!("clippy::{}", lint),
+                    "description": diag.message,
+                    "content": { "body": description(diag) },
+                    "categories": [category(diag.group())],
+                    "location": {
+                        "path": span.file_name,
+                        "positions": {
+                            "begin": { "line": span.line_start, "column": span.column_start },
+                            "end": { "line": span.line_end, "column": span.column_end },
+                        },
+                    },
+                    "severity": if diag.level == "warning" { "minor" } else { "major" },
+                    "fingerprint": fingerprint,
+                }))
+            })
+            .collect();
+
+        serde_json::to_writer_pretty(&mut *out, &issues)?;
+        writeln!(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fingerprint;
+
+    #[test]
+    fn stable_fingerprint() {
+        assert_eq!(fingerprint(&[]), "cbf29ce484222325");
+        assert_eq!(
+            fingerprint(&["needless_return", "src/main.rs", "unneeded `return` statement"]),
+            fingerprint(&["needless_return", "src/main.rs", "unneeded `return` statement"])
+        );
+        assert_ne!(fingerprint(&["ab", "c"]), fingerprint(&["a", "bc"]));
+    }
+}
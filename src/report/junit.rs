@@ -0,0 +1,52 @@
+//! `JUnit` XML, read by most CI servers. Each diagnostic is a failed test case.
+
+use super::{description, xml_escape, Formatter};
+use crate::diagnostic::Diagnostic;
+use std::io;
+
+pub struct JUnit;
+
+impl Formatter for JUnit {
+    fn name(&self) -> &'static str {
+        "junit"
+    }
+
+    fn write(&self, diagnostics: &[Diagnostic], out: &mut dyn io::Write) -> io::Result<()> {
+        // the diagnostics without a lint or a span are not written
+        let cases: Vec<_> = diagnostics
+            .iter()
+            .filter_map(|diag| Some((diag, diag.lint()?, diag.primary_span()?)))
+            .collect();
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            out,
+            r#"<testsuites name="clippy" tests="{0}" failures="{0}">"#,
+            cases.len()
+        )?;
+        writeln!(
+            out,
+            r#"  <testsuite name="clippy" tests="{0}" failures="{0}">"#,
+            cases.len()
+        )?;
+        for (diag, lint, span) in cases {
+            writeln!(
+                out,
+                r#"    <testcase name="clippy::{}" classname="{}:{}:{}">"#,
+                lint,
+                xml_escape(&span.file_name),
+                span.line_start,
+                span.column_start
+            )?;
+            writeln!(
+                out,
+                r#"      <failure type="{}" message="{}">{}</failure>"#,
+                xml_escape(&diag.level),
+                xml_escape(&diag.message),
+                xml_escape(&description(diag))
+            )?;
+            writeln!(out, "    </testcase>")?;
+        }
+        writeln!(out, "  </testsuite>")?;
+        writeln!(out, "</testsuites>")
+    }
+}
@@ -0,0 +1,150 @@
+//! Structured reports of the Clippy diagnostics, for `cargo clippy --report-format <FORMAT>`.
+//!
+//! To add a format, implement [`Formatter`] and add it to [`FORMATTERS`].
+
+mod checkstyle;
+mod code_climate;
+mod junit;
+mod sarif;
+
+use crate::diagnostic::Diagnostic;
+use std::io;
+
+/// A report format.
+pub trait Formatter {
+    /// The name of the format on the command line.
+    fn name(&self) -> &'static str;
+
+    /// Writes the report of `diagnostics` to `out`.
+    fn write(&self, diagnostics: &[Diagnostic], out: &mut dyn io::Write) -> io::Result<()>;
+}
+
+/// All the available report formats.
+const FORMATTERS: &[&dyn Formatter] = &[
+    &checkstyle::Checkstyle,
+    &code_climate::CodeClimate,
+    &junit::JUnit,
+    &sarif::Sarif,
+];
+
+/// The report format named `name`.
+pub fn formatter(name: &str) -> Option<&'static dyn Formatter> {
+    FORMATTERS.iter().copied().find(|formatter| formatter.name() == name)
+}
+
+/// The names of the available report formats.
+pub fn names() -> Vec<&'static str> {
+    FORMATTERS.iter().map(|formatter| formatter.name()).collect()
+}
+
+/// Escapes `s` for use in XML text and attribute values.
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A human readable description of a diagnostic: its message, the group of the lint, the
+/// suggestions to fix it and a link to the documentation of the lint.
+fn description(diag: &Diagnostic) -> String {
+    let mut description = diag.message.clone();
+    if let Some(group) = diag.group() {
+        description.push_str(&format!("\nlint group: {}", group));
+    }
+    for suggestion in diag.suggestions() {
+        description.push_str(&format!(
+            "\n{}: `{}` ({})",
+            suggestion.message, suggestion.replacement, suggestion.applicability
+        ));
+    }
+    if let Some(link) = diag.docs_link() {
+        description.push_str(&format!("\nfor further information visit {}", link));
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{formatter, names, Diagnostic};
+
+    /// A `needless_return` warning with a machine applicable suggestion.
+    fn diagnostic() -> Diagnostic {
+        serde_json::from_str(
+            r#"{
+                "message": "unneeded `return` statement",
+                "code": {"code": "clippy::needless_return"},
+                "level": "warning",
+                "spans": [{
+                    "file_name": "src/main.rs", "byte_start": 20, "byte_end": 29,
+                    "line_start": 2, "line_end": 2, "column_start": 5, "column_end": 14,
+                    "is_primary": true, "label": null,
+                    "suggested_replacement": null, "suggestion_applicability": null
+                }],
+                "children": [{
+                    "message": "remove `return`", "code": null, "level": "help",
+                    "spans": [{
+                        "file_name": "src/main.rs", "byte_start": 20, "byte_end": 29,
+                        "line_start": 2, "line_end": 2, "column_start": 5, "column_end": 14,
+                        "is_primary": true, "label": null,
+                        "suggested_replacement": "1", "suggestion_applicability": "MachineApplicable"
+                    }],
+                    "children": [], "rendered": null
+                }],
+                "rendered": "warning: unneeded `return` statement\n"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn all_formats() {
+        let diagnostics = [diagnostic()];
+        for name in names() {
+            let mut out = Vec::new();
+            formatter(name).unwrap().write(&diagnostics, &mut out).unwrap();
+            let out = String::from_utf8(out).unwrap();
+            assert!(out.contains("needless_return"), "{}: {}", name, out);
+            assert!(out.contains("src/main.rs"), "{}: {}", name, out);
+        }
+    }
+
+    #[test]
+    fn code_climate_unique_fingerprints() {
+        let diagnostics = [diagnostic(), diagnostic()];
+        let mut out = Vec::new();
+        formatter("codeclimate").unwrap().write(&diagnostics, &mut out).unwrap();
+        let issues: Vec<serde_json::Value> = serde_json::from_slice(&out).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+    }
+
+    #[test]
+    fn junit_counts_written_cases() {
+        // `aborting due to previous error` has neither a lint nor a span
+        let summary = serde_json::from_str(
+            r#"{"message": "aborting due to previous error", "code": null, "level": "error",
+                "spans": [], "children": [], "rendered": "error: aborting due to previous error\n"}"#,
+        )
+        .unwrap();
+        let diagnostics = [diagnostic(), summary];
+        let mut out = Vec::new();
+        formatter("junit").unwrap().write(&diagnostics, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.contains(r#"<testsuite name="clippy" tests="1" failures="1">"#),
+            "{}",
+            out
+        );
+        assert_eq!(out.matches("<testcase ").count(), 1, "{}", out);
+    }
+}
@@ -0,0 +1,107 @@
+//! [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html), read by code
+//! scanning interfaces.
+
+use super::Formatter;
+use crate::diagnostic::{docs_link, Diagnostic, Span};
+use serde_json::{json, Value};
+use std::io;
+
+pub struct Sarif;
+
+/// The SARIF region of a span.
+fn region(span: &Span) -> Value {
+    json!({
+        "startLine": span.line_start,
+        "startColumn": span.column_start,
+        "endLine": span.line_end,
+        "endColumn": span.column_end,
+    })
+}
+
+fn level(diag: &Diagnostic) -> &'static str {
+    match diag.level.as_str() {
+        "error" | "error: internal compiler error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    }
+}
+
+impl Formatter for Sarif {
+    fn name(&self) -> &'static str {
+        "sarif"
+    }
+
+    fn write(&self, diagnostics: &[Diagnostic], out: &mut dyn io::Write) -> io::Result<()> {
+        let mut rules: Vec<&str> = diagnostics.iter().filter_map(Diagnostic::lint).collect();
+        rules.sort_unstable();
+        rules.dedup();
+
+        let results: Vec<Value> = diagnostics
+            .iter()
+            .filter_map(|diag| {
+                let lint = diag.lint()?;
+                let span = diag.primary_span()?;
+                let fixes: Vec<Value> = diag
+                    .suggestions()
+                    .iter()
+                    .map(|suggestion| {
+                        json!({
+                            "description": { "text": suggestion.message },
+                            "artifactChanges": [{
+                                "artifactLocation": { "uri": suggestion.span.file_name },
+                                "replacements": [{
+                                    "deletedRegion": region(suggestion.span),
+                                    "insertedContent": { "text": suggestion.replacement },
+                                }],
+                            }],
+                            "properties": { "applicability": suggestion.applicability },
+                        })
+                    })
+                    .collect();
+                Some(json!({
+                    "ruleId": format!("clippy::{}", lint),
+                    "ruleIndex": rules.binary_search(&lint).ok(),
+                    "level": level(diag),
+                    "message": { "text": diag.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": span.file_name },
+                            "region": region(span),
+                        },
+                    }],
+                    "fixes": fixes,
+                }))
+            })
+            .collect();
+
+        let rules: Vec<Value> = rules
+            .iter()
+            .map(|lint| {
+                json!({
+                    "id": format!("clippy::{}", lint),
+                    "name": lint,
+                    "helpUri": docs_link(lint),
+                    "properties": { "group": crate::explain::group(lint) },
+                })
+            })
+            .collect();
+
+        let report = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "clippy",
+                        "informationUri": "https://github.com/rust-lang/rust-clippy",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        });
+        serde_json::to_writer_pretty(&mut *out, &report)?;
+        writeln!(out)
+    }
+}
@@ -0,0 +1,75 @@
+#![feature(once_cell)]
+
+use std::fs;
+
+mod cargo;
+mod scratch;
+
+const MAIN: &str = "fn one() -> i32 {
+    return 1;
+}
+
+fn main() {
+    one();
+}
+";
+
+const FIXED: &str = "fn one() -> i32 {
+    1
+}
+
+fn main() {
+    one();
+}
+";
+
+const NEW_FINDING: &str = "fn one() -> i32 {
+    return 1;
+}
+
+fn two() -> i32 {
+    return 2;
+}
+
+fn main() {
+    one();
+    two();
+}
+";
+
+#[test]
+fn baseline_with_denied_warnings() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = scratch::package("baseline", MAIN);
+
+    let output = scratch::cargo_clippy(&dir, &["--write-baseline", "baseline.json"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("recorded 1 Clippy finding(s) in the baseline"));
+
+    // the denied finding is in the baseline, so it doesn't fail the build
+    let output = scratch::cargo_clippy(&dir, &["--baseline", "baseline.json", "--", "-D", "warnings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(!stderr.contains("error"));
+    assert!(!stderr.contains("warning:"));
+    assert!(stderr.contains("1 Clippy warning(s) suppressed by the baseline"));
+
+    // a finding missing from the baseline does
+    fs::write(dir.join("src/main.rs"), NEW_FINDING).unwrap();
+    let output = scratch::cargo_clippy(&dir, &["--baseline", "baseline.json", "--", "-D", "warnings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert_eq!(stderr.matches("unneeded `return` statement").count(), 1);
+    assert!(stderr.contains("src/main.rs:6:5"));
+    assert!(stderr.contains("could not compile `baseline`"));
+
+    fs::write(dir.join("src/main.rs"), FIXED).unwrap();
+    let output = scratch::cargo_clippy(&dir, &["--baseline", "baseline.json", "--", "-D", "warnings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(!stderr.contains("error"));
+    assert!(stderr.contains("note: stale baseline entry, `clippy::needless_return` doesn't fire 1 time(s) anymore"));
+}
@@ -0,0 +1,67 @@
+#![feature(once_cell)]
+
+use std::fs;
+
+mod cargo;
+mod scratch;
+
+const COMMITTED: &str = "fn old() -> i32 {
+    return 1;
+}
+
+fn main() {
+    old();
+}
+";
+
+const CHANGED: &str = "fn old() -> i32 {
+    return 1;
+}
+
+fn new() -> i32 {
+    return 2;
+}
+
+fn main() {
+    old();
+    new();
+}
+";
+
+/// Runs `cargo clippy --changed-since HEAD` on a crate changed since its commit, with the git
+/// configuration `config`, and checks that only the changed function is linted.
+fn check_changed_since(name: &str, config: &[(&str, &str)]) {
+    let dir = scratch::package(name, COMMITTED);
+    for (key, value) in config {
+        scratch::git(&dir, &["config", key, value]);
+    }
+    fs::write(dir.join("src/main.rs"), CHANGED).unwrap();
+
+    let output = scratch::cargo_clippy(
+        &dir,
+        &["--changed-since", "HEAD", "--", "-W", "clippy::needless_return"],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    // only the `return` of the new function is on a changed line
+    assert_eq!(stderr.matches("unneeded `return` statement").count(), 1);
+    assert!(stderr.contains("src/main.rs:6:5"));
+    assert!(!stderr.contains("src/main.rs:2:5"));
+}
+
+#[test]
+fn changed_since_filters_diagnostics() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    check_changed_since("changed_since", &[]);
+}
+
+#[test]
+fn changed_since_without_diff_prefixes() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    check_changed_since("changed_since_noprefix", &[("diff.noprefix", "true")]);
+    check_changed_since("changed_since_mnemonic_prefix", &[("diff.mnemonicPrefix", "true")]);
+}
@@ -215,6 +215,7 @@ fn run_ui_cargo(config: &mut compiletest::Config) {
                         _ => continue,
                     }
                     set_var("CLIPPY_CONF_DIR", case.path());
+                    set_var("CARGO_MANIFEST_DIR", case.path());
                     let paths = compiletest::common::TestPaths {
                         file: file_path,
                         base: config.src_base.clone(),
@@ -243,10 +244,12 @@ fn run_ui_cargo(config: &mut compiletest::Config) {
 
     let current_dir = env::current_dir().unwrap();
     let conf_dir = var("CLIPPY_CONF_DIR").unwrap_or_default();
+    let manifest_dir = var("CARGO_MANIFEST_DIR").unwrap_or_default();
     let filter = env::var("TESTNAME").ok();
     let res = run_tests(&config, &filter, tests);
     env::set_current_dir(current_dir).unwrap();
     set_var("CLIPPY_CONF_DIR", conf_dir);
+    set_var("CARGO_MANIFEST_DIR", manifest_dir);
 
     match res {
         Ok(true) => {},
@@ -0,0 +1,120 @@
+#![feature(once_cell)]
+
+use scratch::{cargo_clippy, git, package};
+use std::fs;
+
+mod cargo;
+mod scratch;
+
+const MAIN: &str = "fn One() -> i32 {
+    return 1;
+}
+
+fn main() {
+    One();
+}
+";
+
+const FIXED: &str = "fn One() -> i32 {
+    1
+}
+
+fn main() {
+    One();
+}
+";
+
+/// The suggestion of `from_iter_instead_of_collect` mangles the path with a turbofish, so that the
+/// code doesn't compile anymore.
+const BROKEN_BY_FIX: &str = "use std::iter::FromIterator;
+
+fn main() {
+    let _ = Vec::<std::string::String>::from_iter(vec![String::new()].into_iter());
+}
+";
+
+#[test]
+fn fix_with_denied_warnings() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = package("denied_warnings", MAIN);
+
+    // the denied lints are errors, but the code compiles
+    let output = cargo_clippy(&dir, &["--fix", "--", "-D", "warnings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("doesn't compile"));
+    assert!(stderr.contains("applied 1 Clippy suggestion(s) to 1 file(s)"));
+    // `non_snake_case` is still denied
+    assert!(!output.status.success());
+    assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), FIXED);
+}
+
+#[test]
+fn fix_uncommitted_changes() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = package("uncommitted_changes", MAIN);
+    let changed = format!("{}// changed\n", MAIN);
+    fs::write(dir.join("src/main.rs"), &changed).unwrap();
+
+    let output = cargo_clippy(&dir, &["--fix"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("src/main.rs (dirty)"));
+    assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), changed);
+
+    git(&dir, &["add", "-A"]);
+    let output = cargo_clippy(&dir, &["--fix"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("src/main.rs (staged)"));
+
+    let output = cargo_clippy(&dir, &["--fix", "--allow-staged"]);
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("src/main.rs")).unwrap(),
+        format!("{}// changed\n", FIXED)
+    );
+
+    // the file is dirty now
+    let output = cargo_clippy(&dir, &["--fix", "--allow-dirty"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn fix_breaking_compilation() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = package("breaking_compilation", BROKEN_BY_FIX);
+
+    let output = cargo_clippy(
+        &dir,
+        &[
+            "--fix",
+            "--fix-lints=from_iter_instead_of_collect",
+            "--fix-applicability=maybe-incorrect",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the original files were restored"));
+    assert_eq!(fs::read(dir.join("src/main.rs")).unwrap(), BROKEN_BY_FIX.as_bytes());
+}
+
+#[test]
+fn emit_patch() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = package("emit_patch", MAIN);
+
+    let output = cargo_clippy(&dir, &["--emit-patch", "clippy.diff"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("wrote 1 Clippy suggestion(s) to the patch `clippy.diff`"));
+    // the tree is left untouched
+    assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), MAIN);
+
+    git(&dir, &["apply", "--check", "clippy.diff"]);
+    git(&dir, &["apply", "clippy.diff"]);
+    assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), FIXED);
+}
@@ -0,0 +1,41 @@
+#![feature(once_cell)]
+
+use std::fs;
+
+mod cargo;
+mod scratch;
+
+const MAIN: &str = "fn main() {
+    let _ = 1;
+}
+";
+
+#[test]
+fn lint_timings_with_report_format() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = scratch::package("lint_timings_report", MAIN);
+
+    // cargo reads the messages of rustc on stdout in this mode, the table must be on stderr
+    let output = scratch::cargo_clippy(&dir, &["--report-format", "sarif", "--", "--lint-timings"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(stderr.contains("Clippy lint pass timings for crate `lint_timings_report`"));
+}
+
+#[test]
+fn lint_timings_json_to_file() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let dir = scratch::package("lint_timings_file", MAIN);
+    let path = dir.join("timings.jsonl");
+    let arg = format!("--lint-timings=json:{}", path.display());
+
+    let output = scratch::cargo_clippy(&dir, &["--report-format", "sarif", "--", &arg]);
+    assert!(output.status.success());
+    let timings = fs::read_to_string(&path).unwrap();
+    assert_eq!(timings.lines().count(), 1);
+    assert!(timings.starts_with(r#"{"crate":"lint_timings_file","#));
+}
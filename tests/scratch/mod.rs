@@ -0,0 +1,60 @@
+//! Scratch crates in a git repository, for the tests running `cargo clippy` on a whole package.
+
+use crate::cargo;
+use std::fs;
+use std::lazy::SyncLazy;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+pub static CLIPPY_PATH: SyncLazy<PathBuf> = SyncLazy::new(|| cargo::TARGET_LIB.join("cargo-clippy"));
+
+/// Runs git with `args` in `dir`, panicking if it fails. The author of the commits is set, so that
+/// it doesn't depend on the user's configuration.
+pub fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(&["-c", "user.name=clippy", "-c", "user.email=clippy@example.com"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+/// Creates the binary crate `name` in the target directory, with the `main` source file committed
+/// to a new git repository.
+pub fn package(name: &str, main: &str) -> PathBuf {
+    let dir = cargo::CARGO_TARGET_DIR.join("scratch").join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2018\"\npublish = false\n\n[workspace]\n",
+            name
+        ),
+    )
+    .unwrap();
+    fs::write(dir.join(".gitignore"), "/target\nCargo.lock\n").unwrap();
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+    git(&dir, &["init", "-q"]);
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-q", "-m", "initial"]);
+    dir
+}
+
+/// Runs `cargo clippy` with `args` in `dir`, printing its status and its messages.
+pub fn cargo_clippy(dir: &Path, args: &[&str]) -> Output {
+    let output = Command::new(&*CLIPPY_PATH)
+        .current_dir(dir)
+        .env("CARGO_INCREMENTAL", "0")
+        .env_remove("CARGO_TARGET_DIR")
+        .arg("clippy")
+        .args(args)
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    output
+}
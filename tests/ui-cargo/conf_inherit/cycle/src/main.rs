@@ -0,0 +1,2 @@
+// compile-flags: --crate-name=cycle
+fn main() {}
@@ -0,0 +1,14 @@
+// compile-flags: --crate-name=conf_inherit
+// rustc-env:CLIPPY_ARGS=--print-conf__CLIPPY_HACKERY__
+// normalize-stderr-test: "`[^`]*/conf_inherit/clippy.toml`" -> "`$$SRC_DIR/../clippy.toml`"
+#![allow(unused_variables)]
+#![warn(clippy::blacklisted_name)]
+
+fn main() {
+    // from the inherited `clippy.toml`
+    let toto = 42;
+    // from `inherit/clippy.toml`
+    let titi = 42;
+    // the default list is replaced by the configured one
+    let foo = 42;
+}
@@ -0,0 +1,9 @@
+// compile-flags: --crate-name=msrv
+#![warn(clippy::manual_strip)]
+
+fn main() {
+    let s = "abc";
+    if s.starts_with("ab") {
+        s["ab".len()..].to_string();
+    }
+}
@@ -1,7 +1,9 @@
 // rustc-env:RUST_BACKTRACE=0
+// rustc-env:CLIPPY_ICE_DIR=target/debug/test_build_base
 // normalize-stderr-test: "Clippy version: .*" -> "Clippy version: foo"
 // normalize-stderr-test: "internal_lints.rs:\d*:\d*" -> "internal_lints.rs"
 // normalize-stderr-test: "', .*clippy_lints" -> "', clippy_lints"
+// normalize-stderr-test: "written to `.*`" -> "written to `$$DIR`"
 
 #![deny(clippy::internal)]
 
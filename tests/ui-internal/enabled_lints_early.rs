@@ -0,0 +1,6 @@
+//! The early pass of `clippy::produce_ice`, which panics on the function below whatever the level
+//! of the lint is, is skipped since the lint is allowed.
+
+fn it_looks_like_you_are_trying_to_kill_clippy() {}
+
+fn main() {}
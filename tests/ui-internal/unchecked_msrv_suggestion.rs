@@ -0,0 +1,81 @@
+#![deny(clippy::internal)]
+// the MSRV checks are recognized by their paths in `clippy_lints::utils`
+#![crate_name = "clippy_lints"]
+
+#[allow(unused_variables)]
+mod utils {
+    pub fn meets_msrv(msrv: Option<&str>, lint_msrv: &str) -> bool {
+        true
+    }
+
+    pub fn std_item_meets_msrv(path: &[&str], msrv: Option<&str>) -> bool {
+        true
+    }
+
+    pub struct StdItem;
+
+    impl StdItem {
+        pub fn meets_msrv(&self, msrv: Option<&str>) -> bool {
+            true
+        }
+    }
+}
+
+use utils::{meets_msrv, std_item_meets_msrv, StdItem};
+
+#[allow(unused_variables)]
+fn span_lint_and_sugg(msg: &str, sugg: String) {}
+
+fn suggest_as_deref(recv: &str) {
+    span_lint_and_sugg("called `.as_ref().map(Deref::deref)`", format!("{}.as_deref()", recv));
+}
+
+fn suggest_strip_prefix(recv: &str) {
+    let sugg = format!("{}.strip_prefix(\"x\")", recv);
+    span_lint_and_sugg("stripping a prefix manually", sugg);
+}
+
+fn suggest_with_msrv_check(recv: &str, msrv: Option<&str>) {
+    if std_item_meets_msrv(&["core", "option", "Option", "as_deref"], msrv) {
+        span_lint_and_sugg("called `.as_ref().map(Deref::deref)`", format!("{}.as_deref()", recv));
+    }
+}
+
+fn suggest_with_cached_msrv_check(recv: &str, as_deref: &StdItem, msrv: Option<&str>) {
+    if as_deref.meets_msrv(msrv) {
+        span_lint_and_sugg("called `.as_ref().map(Deref::deref)`", format!("{}.as_deref()", recv));
+    }
+}
+
+fn suggest_with_hand_written_msrv(recv: &str, msrv: Option<&str>) {
+    // the version may be wrong, only the checks of the stability of the method count
+    if meets_msrv(msrv, "1.40.0") {
+        span_lint_and_sugg("called `.as_ref().map(Deref::deref)`", format!("{}.as_deref()", recv));
+    }
+}
+
+fn suggest_old_method(recv: &str) {
+    // `Option::map` is stable since Rust 1.0.0
+    span_lint_and_sugg("called `.and_then(|x| Some(..))`", format!("{}.map(..)", recv));
+}
+
+fn suggest_shared_name(recv: &str) {
+    // `Option::zip` is stable since Rust 1.46.0, but `Iterator::zip` since Rust 1.0.0
+    span_lint_and_sugg("zipping options manually", format!("{}.zip(..)", recv));
+}
+
+fn quoted_method(recv: &str) {
+    // methods quoted in messages are not suggested
+    span_lint_and_sugg("called `map(..).flatten()`", format!("{}.and_then(..)", recv));
+}
+
+fn chained_method(recv: &str) {
+    // only the method called on the formatted receiver is recognized
+    span_lint_and_sugg("called `.len() == 0`", format!("{}.is_empty().then(..)", recv));
+}
+
+fn no_suggestion() -> &'static str {
+    "x.as_deref()"
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::expect_used)]
+
+fn reported(x: Option<u8>) {
+    x.unwrap();
+}
+
+#[test]
+fn test_fn() {
+    let x: Option<u8> = None;
+    x.unwrap();
+    assert_eq!(x.unwrap(), 1);
+    // not allowed in tests
+    x.expect("reported");
+}
+
+struct Foo;
+
+#[cfg(test)]
+impl Foo {
+    fn helper(x: Option<u8>) {
+        x.unwrap();
+    }
+}
+
+impl Foo {
+    fn reported(x: Option<u8>) {
+        x.unwrap();
+    }
+
+    #[cfg(test)]
+    fn helper_fn(x: Option<u8>) {
+        x.unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper(x: Option<u8>) {
+        x.unwrap();
+    }
+
+    #[test]
+    fn test_fn() {
+        helper(Some(1));
+    }
+}
+
+mod nested {
+    fn reported(x: Option<u8>) {
+        x.unwrap();
+    }
+
+    #[test]
+    fn test_fn() {
+        let x: Option<u8> = None;
+        x.unwrap();
+    }
+}
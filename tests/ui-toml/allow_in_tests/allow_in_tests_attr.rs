@@ -0,0 +1,24 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::expect_used)]
+
+#[test]
+fn test_fn() {
+    let x: Option<u8> = None;
+    x.unwrap();
+    x.expect("reported");
+}
+
+#[clippy::conf(allow_in_tests("expect_used"))]
+mod expect_allowed {
+    #[test]
+    fn test_fn() {
+        let x: Option<u8> = None;
+        x.expect("allowed");
+        // the lints of the attribute replace the ones of the configuration file
+        x.unwrap();
+    }
+
+    fn reported(x: Option<u8>) {
+        x.expect("reported");
+    }
+}
@@ -0,0 +1,9 @@
+// rustc-env:UNSTABLE_RUSTDOC_TEST_PATH=src/lib.rs
+#![warn(clippy::unwrap_used, clippy::expect_used)]
+
+fn main() {
+    let x: Option<u8> = None;
+    x.unwrap();
+    // not allowed in tests
+    x.expect("reported");
+}
@@ -0,0 +1,18 @@
+// compile-flags: --test
+#![warn(clippy::print_stdout)]
+
+fn reported() {
+    println!("reported");
+}
+
+#[test]
+fn test_fn() {
+    println!("allowed");
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        println!("allowed");
+    }
+}
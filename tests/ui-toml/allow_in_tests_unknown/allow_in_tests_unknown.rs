@@ -0,0 +1,7 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_used)]
+
+#[test]
+fn test() {
+    let _ = Some(1).unwrap();
+}
@@ -0,0 +1,46 @@
+// edition:2018
+#![warn(clippy::await_holding_invalid_type)]
+
+struct Transaction;
+
+impl Transaction {
+    fn commit(self) {}
+}
+
+async fn baz() -> u32 {
+    42
+}
+
+async fn bad() -> u32 {
+    let transaction = Transaction;
+    let value = baz().await;
+    transaction.commit();
+    value
+}
+
+async fn good() -> u32 {
+    {
+        let transaction = Transaction;
+        transaction.commit();
+    }
+    baz().await
+}
+
+async fn bad_string() -> usize {
+    let s = String::from("held");
+    baz().await;
+    s.len()
+}
+
+async fn good_str() -> usize {
+    let s = "not configured";
+    baz().await;
+    s.len()
+}
+
+fn main() {
+    let _ = bad();
+    let _ = good();
+    let _ = bad_string();
+    let _ = good_str();
+}
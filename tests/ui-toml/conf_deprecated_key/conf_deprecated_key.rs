@@ -1,4 +1,4 @@
-// error-pattern: error reading Clippy's configuration file: found deprecated field
-// `cyclomatic-complexity-threshold`. Please use `cognitive-complexity-threshold` instead.
+// error-pattern: found deprecated field `cyclomatic-complexity-threshold`. Please use
+// `cognitive-complexity-threshold` instead.
 
 fn main() {}
@@ -0,0 +1,15 @@
+#![warn(clippy::needless_return)]
+
+mod bindings {
+    include!("generated/bindings.rs");
+}
+
+mod messages {
+    include!("marked/messages.rs");
+}
+
+fn reported() -> i32 {
+    return 1;
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![warn(clippy::needless_return)]
+
+#[clippy::conf(exclude("vendored/**"))]
+#[path = "vendored/lib.rs"]
+mod vendored;
+
+// the globs of the attribute replace the ones of the configuration file
+#[clippy::conf(exclude("vendored/**"))]
+#[path = "generated/bindings.rs"]
+mod bindings;
+
+fn reported() -> i32 {
+    return 1;
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+pub fn excluded_by_glob() -> i32 {
+    return 1;
+}
@@ -0,0 +1,5 @@
+// This file is @generated by a code generator.
+
+pub fn excluded_as_generated() -> i32 {
+    return 1;
+}
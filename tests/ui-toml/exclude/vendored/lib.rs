@@ -0,0 +1,3 @@
+pub fn excluded_by_attribute() -> i32 {
+    return 1;
+}
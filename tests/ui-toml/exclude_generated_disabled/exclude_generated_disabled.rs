@@ -0,0 +1,7 @@
+#![warn(clippy::needless_return)]
+
+mod messages {
+    include!("../exclude/marked/messages.rs");
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// The lint levels come from `clippy.toml`, there are no lint attributes in this file.
+
+mod foo {
+    // `module_name_repetitions` is allowed even though it's part of `pedantic`
+    pub fn foo_bar() {}
+}
+
+fn main() {
+    let x: Option<u8> = None;
+    x.unwrap();
+    foo::foo_bar();
+    let y: u64 = 1;
+    let _ = y as f64;
+    // `unused_variables` is a rustc lint, allowed without the `clippy::` prefix
+    let unused = 1;
+}
@@ -0,0 +1,11 @@
+// rustc-env:CLIPPY_ARGS=--clippy-profile=ci__CLIPPY_HACKERY__
+// The lint levels come from `clippy.toml`, with the `ci` profile applied on top of `[lints]`.
+
+fn main() {
+    // `unwrap_used` is allowed by the profile
+    let x: Option<u8> = None;
+    x.unwrap();
+    // `pedantic` is still set by `[lints]`
+    let y: u64 = 1;
+    let _ = y as f64;
+}
@@ -0,0 +1,25 @@
+#![warn(clippy::disallowed_macro)]
+
+macro_rules! make_static {
+    ($name:ident: $ty:ty = $value:expr) => {
+        static $name: $ty = $value;
+    };
+}
+
+macro_rules! log {
+    ($($arg:tt)*) => {
+        println!($($arg)*)
+    };
+}
+
+make_static!(VALUE: u32 = 1);
+
+fn main() {
+    println!("{}", VALUE);
+    print!("not disallowed");
+    let _ = dbg!(1);
+    let _ = vec![dbg!(2), 3];
+    assert_eq!(VALUE, 1);
+    log!("nested in a local macro");
+    log!("linted once in the definition");
+}
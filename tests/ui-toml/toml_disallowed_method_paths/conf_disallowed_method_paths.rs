@@ -0,0 +1,41 @@
+#![warn(clippy::disallowed_method)]
+
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+/// Overrides `Read::read_to_string`.
+struct Text(&'static str);
+
+impl Read for Text {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.0.len().min(buf.len());
+        buf[..len].copy_from_slice(&self.0.as_bytes()[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
+        buf.push_str(self.0);
+        Ok(std::mem::take(&mut self.0).len())
+    }
+}
+
+fn read_all<R: Read>(mut reader: R) -> String {
+    let mut s = String::new();
+    reader.read_to_string(&mut s).unwrap();
+    s
+}
+
+fn main() {
+    let _ = Duration::from_secs(1).as_secs();
+    let _ = Duration::from_secs(1).as_millis();
+    let _ = Instant::now();
+
+    let _ = std::env::var("HOME");
+    let _ = std::env::args();
+    let _ = std::process::id();
+
+    let mut s = String::new();
+    Text("text").read_to_string(&mut s).unwrap();
+    read_all(std::io::empty());
+}
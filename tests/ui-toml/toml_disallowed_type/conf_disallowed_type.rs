@@ -0,0 +1,26 @@
+#![warn(clippy::disallowed_type)]
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+struct Local(u32);
+
+struct Config {
+    values: HashMap<String, u32>,
+    order: BTreeMap<String, u32>,
+}
+
+fn lock(values: &Mutex<Vec<u32>>) -> usize {
+    values.lock().unwrap().len()
+}
+
+fn main() {
+    let mut map = HashMap::new();
+    map.insert(1, 2);
+    let _: std::sync::Mutex<u32> = Mutex::new(0);
+    let _ = std::time::Duration::from_secs(1);
+    let _ = std::cmp::Ordering::Less;
+    let _ = Local(1);
+    let _ = &map as *const HashMap<i32, i32>;
+    let _ = BTreeMap::<u32, u32>::new();
+}
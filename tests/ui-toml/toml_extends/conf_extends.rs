@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![warn(clippy::blacklisted_name)]
+
+fn main() {
+    // from `shared.toml`
+    let toto = 42;
+    let tata = 42;
+    // from `clippy.toml`
+    let titi = 42;
+    // the default list is replaced by the configured one
+    let foo = 42;
+}
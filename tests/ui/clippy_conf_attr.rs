@@ -0,0 +1,54 @@
+#![warn(
+    clippy::too_many_arguments,
+    clippy::too_many_lines,
+    clippy::blacklisted_name,
+    clippy::large_enum_variant,
+    clippy::cognitive_complexity
+)]
+#![allow(clippy::many_single_char_names, clippy::unused_unit)]
+
+fn three_args(_a: u8, _b: u8, _c: u8) {}
+
+#[clippy::conf(too_many_lines_threshold = 1)]
+fn too_long() {
+    let _x = 1;
+    let _y = 2;
+}
+
+#[clippy::conf(too_many_arguments_threshold = 2)]
+mod strict {
+    fn three_args(_a: u8, _b: u8, _c: u8) {}
+
+    // the innermost value applies
+    #[clippy::conf(too_many_arguments_threshold = 3)]
+    fn allowed(_a: u8, _b: u8, _c: u8) {}
+}
+
+#[clippy::conf(blacklisted_names("toto"))]
+fn names() {
+    let toto = 1;
+    let foo = 2;
+}
+
+fn default_names() {
+    let toto = 1;
+    let foo = 2;
+}
+
+#[clippy::conf(enum_variant_size_threshold = 2000)]
+enum Large {
+    A(i32),
+    B([u8; 1024]),
+}
+
+enum LargeDefault {
+    A(i32),
+    B([u8; 1024]),
+}
+
+#[clippy::conf(cognitive_complexity_threshold = 1)]
+fn complex(x: bool) -> u8 {
+    if x { 1 } else { 2 }
+}
+
+fn main() {}
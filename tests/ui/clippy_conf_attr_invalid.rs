@@ -0,0 +1,24 @@
+#![warn(clippy::too_many_lines)]
+
+#[clippy::conf(too_many_line_threshold = 1)]
+fn unknown_key() {}
+
+#[clippy::conf(msrv = "1.40")]
+fn not_overridable() {}
+
+#[clippy::conf(too_many_lines_threshold = "one")]
+fn invalid_value() {}
+
+#[clippy::conf(blacklisted_names(1, 2))]
+fn invalid_list() {}
+
+#[clippy::conf(too_many_lines_threshold = 1.5)]
+fn invalid_literal() {}
+
+#[clippy::conf(too_many_lines_threshold)]
+fn missing_value() {}
+
+#[clippy::conf = "too_many_lines_threshold"]
+fn not_a_list() {}
+
+fn main() {}
@@ -0,0 +1,67 @@
+// edition:2018
+#![feature(custom_inner_attributes)]
+#![warn(
+    clippy::disallowed_method,
+    clippy::disallowed_type,
+    clippy::disallowed_macro,
+    clippy::await_holding_invalid_type
+)]
+#![clippy::conf(disallowed_methods("std::env::var"))]
+
+fn crate_list() {
+    let _ = std::env::var("HOME");
+    let _ = std::env::current_dir();
+}
+
+// the list of the attribute replaces the one of the crate
+#[clippy::conf(disallowed_methods("std::env::current_dir"))]
+fn item_list() {
+    let _ = std::env::var("HOME");
+    let _ = std::env::current_dir();
+}
+
+#[clippy::conf(disallowed_methods("std::env::*"))]
+fn glob() {
+    let _ = std::env::args();
+}
+
+#[clippy::conf(disallowed_types("std::collections::HashMap"))]
+mod types {
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn disallowed() -> HashMap<u8, u8> {
+        HashMap::new()
+    }
+
+    #[clippy::conf(disallowed_types("std::collections::BTreeMap"))]
+    pub fn inner() {
+        let _: BTreeMap<u8, u8> = BTreeMap::new();
+        let _: HashMap<u8, u8> = HashMap::new();
+    }
+}
+
+#[clippy::conf(disallowed_macros("std::println"))]
+fn macros() {
+    println!("disallowed");
+}
+
+fn macros_allowed() {
+    println!("allowed");
+}
+
+async fn baz() {}
+
+#[clippy::conf(await_holding_invalid_types("std::string::String"))]
+async fn holding() -> usize {
+    let s = String::new();
+    baz().await;
+    s.len()
+}
+
+async fn holding_allowed() -> usize {
+    let s = String::new();
+    baz().await;
+    s.len()
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+//! The passes of the lints which are only enabled on some items still run.
+
+mod inner {
+    #[warn(clippy::integer_division)]
+    pub fn divide(a: i32) -> i32 {
+        a / 2
+    }
+}
+
+#[cfg_attr(all(), warn(clippy::integer_arithmetic))]
+fn increment(a: i32) -> i32 {
+    a + 1
+}
+
+macro_rules! pedantic {
+    ($item:item) => {
+        #[warn(clippy::pedantic)]
+        $item
+    };
+}
+
+pedantic! {
+    fn widen(a: u8) -> u16 {
+        a as u16
+    }
+}
+
+fn main() {
+    inner::divide(4);
+    increment(1);
+    widen(1);
+    let _ = 1 / 2;
+}
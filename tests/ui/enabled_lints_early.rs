@@ -0,0 +1,21 @@
+//! The early passes of the lints which are only enabled by the attributes written by a macro of
+//! the crate still run.
+
+macro_rules! pedantic {
+    ($item:item) => {
+        #[warn(clippy::pedantic)]
+        $item
+    };
+}
+
+pedantic! {
+    fn answer() -> u32 {
+        let answer = 42;
+        fn unused() {}
+        answer
+    }
+}
+
+fn main() {
+    answer();
+}
@@ -0,0 +1,34 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.44"]
+#![warn(clippy::incompatible_msrv)]
+
+use std::num::NonZeroU8;
+
+fn main() {
+    // stable since 1.45.0
+    let _ = "abc".strip_prefix("a");
+    // stable since 1.50.0
+    let _ = true.then(|| 1);
+    // paths
+    let _ = std::f32::consts::TAU;
+    let _ = str::strip_prefix("abc", "a");
+    // trait items
+    let _ = (0..3).reduce(|a, b| a + b);
+    // stable since 1.43.0
+    let _ = u32::MAX;
+    let _ = NonZeroU8::new(1);
+}
+
+#[clippy::msrv = "1.50"]
+fn newer_msrv() {
+    let _ = "abc".strip_prefix("a");
+    let _ = true.then(|| 1);
+}
+
+// in external macros
+fn macros() {
+    let _ = format!("{}", 1);
+    let _ = vec![1, 2];
+    for _ in 0..1 {}
+    let _ = Some(1).map(|x| x + 1);
+}
@@ -123,6 +123,30 @@ fn missing_const_for_fn() -> i32 {
     1
 }
 
+fn map_clone() {
+    // `copied` is stable since Rust 1.36.0, `cloned` is suggested instead
+    let _: Vec<i32> = [1, 2, 3].iter().map(|x| *x).collect();
+}
+
+fn manual_flatten() {
+    let x = vec![Some(1), Some(2), None];
+    for n in x {
+        if let Some(y) = n {
+            println!("{}", y);
+        }
+    }
+}
+
+fn map_collect_result_unit() {
+    let _: Result<(), &str> = (0..3).map(|_| Ok(())).collect();
+}
+
+#[warn(clippy::transmute_float_to_int)]
+fn transmute_float_to_int() {
+    // the lint fires without suggesting `to_bits`, which is stable since Rust 1.20.0
+    let _: u32 = unsafe { std::mem::transmute(1f32) };
+}
+
 fn main() {
     filter_map_next();
     checked_conversion();
@@ -138,6 +162,10 @@ fn main() {
     replace_with_default();
     map_unwrap_or();
     missing_const_for_fn();
+    map_clone();
+    manual_flatten();
+    map_collect_result_unit();
+    transmute_float_to_int();
 }
 
 mod meets_msrv {
@@ -0,0 +1,51 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.44"]
+#![warn(clippy::manual_strip)]
+
+fn strip(s: &str) {
+    if s.starts_with("ab") {
+        s["ab".len()..].to_string();
+    }
+}
+
+#[clippy::msrv = "1.45"]
+fn strip_on_fn(s: &str) {
+    if s.starts_with("ab") {
+        s["ab".len()..].to_string();
+    }
+}
+
+fn strip_after_fn(s: &str) {
+    if s.starts_with("ab") {
+        s["ab".len()..].to_string();
+    }
+}
+
+#[clippy::msrv = "1.45"]
+mod module {
+    fn strip_in_mod(s: &str) {
+        if s.starts_with("ab") {
+            s["ab".len()..].to_string();
+        }
+    }
+
+    #[clippy::msrv = "1.44"]
+    fn strip_in_nested_fn(s: &str) {
+        if s.starts_with("ab") {
+            s["ab".len()..].to_string();
+        }
+    }
+}
+
+struct S;
+
+#[clippy::msrv = "1.45"]
+impl S {
+    fn strip_in_impl(s: &str) {
+        if s.starts_with("ab") {
+            s["ab".len()..].to_string();
+        }
+    }
+}
+
+fn main() {}
@@ -3,6 +3,7 @@
 # Build the gh-pages
 
 from collections import OrderedDict
+import os
 import re
 import sys
 import json
@@ -64,7 +65,7 @@ def parse_lint_def(lint):
 
 
 def main():
-    lintlist, configs = parse_all()
+    lintlist, configs, general = parse_all()
     lints = {}
     for lint in lintlist:
         lints[lint.name] = parse_lint_def(lint)
@@ -79,6 +80,12 @@ def main():
         json.dump(lints, fp, indent=2)
         log.info("wrote JSON for great justice")
 
+    # the configuration which isn't about particular lints, next to the lints
+    conffile = os.path.join(os.path.dirname(outfile), "configuration.json")
+    with open(conffile, "w") as fp:
+        json.dump([config._asdict() for config in general], fp, indent=2)
+        log.info("wrote the general configuration to %s", conffile)
+
 
 if __name__ == "__main__":
     main()
@@ -12,9 +12,8 @@ Config = collections.namedtuple('Config', 'name ty doc default')
 
 lintname_re = re.compile(r'''pub\s+([A-Z_][A-Z_0-9]*)''')
 group_re = re.compile(r'''\s*([a-z_][a-z_0-9]+)''')
-conf_re = re.compile(r'''define_Conf! {\n([^}]*)\n}''', re.MULTILINE)
-confvar_re = re.compile(
-    r'''/// Lint: ([\w,\s]+)\. (.*)\n\s*\([^,]+,\s+"([^"]+)":\s+([^,]+),\s+([^\.\)]+).*\),''', re.MULTILINE)
+conf_re = re.compile(r'''define_Conf! {\n(.*?)\n}\n''', re.DOTALL)
+confvar_re = re.compile(r'''/// (?:Lint: ([\w,\s]+)\.|General:) (.*)''')
 comment_re = re.compile(r'''\s*/// ?(.*)''')
 
 lint_levels = {
@@ -86,17 +85,56 @@ def parse_lints(lints, filepath):
 
 
 def parse_configs(path):
+    """Returns the configuration values of the lints, by lint, and the general ones, like
+    `parse_configs` in `build.rs`."""
     configs = {}
+    general = []
     with open(os.path.join(path, 'utils/conf.rs')) as fp:
         contents = fp.read()
 
     match = re.search(conf_re, contents)
-    confvars = re.findall(confvar_re, match.group(1))
-
-    for (lints, doc, name, ty, default) in confvars:
-        for lint in lints.split(','):
-            configs[lint.strip().lower()] = Config(name.replace("_", "-"), ty, doc, default)
-    return configs
+    lines = (line.strip() for line in match.group(1).split('\n'))
+    for line in lines:
+        m = confvar_re.match(line)
+        if not m:
+            continue
+        lints, doc = m.groups()
+
+        # the entry may span several lines, e.g. `doc-valid-idents`
+        entry = ''
+        for line in lines:
+            entry += line + ' '
+            if line.endswith('),'):
+                break
+        entry = entry.strip()
+        if entry.endswith('),'):
+            entry = entry[:-2]
+        name, _, rest = entry.partition('"')[2].partition('": ')
+
+        # the type ends at the first comma that isn't part of its generic arguments
+        depth = 0
+        end = len(rest)
+        for i, c in enumerate(rest):
+            if c == '<':
+                depth += 1
+            elif c == '>':
+                depth -= 1
+            elif c == ',' and depth == 0:
+                end = i
+                break
+        default = rest[end:].lstrip(',').strip()
+        suffix = '.iter().map(ToString::to_string).collect()'
+        if default.endswith(suffix):
+            default = default[:-len(suffix)]
+        default = ' '.join(default.split()).replace(', ]', ']')
+
+        config = Config(name.replace('_', '-'), rest[:end].strip(), doc, default)
+        if lints is None:
+            general.append(config)
+        else:
+            for lint in lints.split(','):
+                configs[lint.strip().lower()] = config
+    return configs, general
 
 
 def parse_all(path="clippy_lints/src"):
@@ -108,7 +146,7 @@ def parse_all(path="clippy_lints/src"):
 
     log.info("got %s lints", len(lints))
 
-    configs = parse_configs(path)
-    log.info("got %d configs", len(configs))
+    configs, general = parse_configs(path)
+    log.info("got %d configs and %d general configs", len(configs), len(general))
 
-    return lints, configs
+    return lints, configs, general